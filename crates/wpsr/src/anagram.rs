@@ -2,23 +2,21 @@ use std::collections::{HashMap, VecDeque};
 
 use colorful::Colorful;
 
-use crate::{DEFAULT_SOURCE_DIR, DEFAULT_WORDS_SOURCE_FILE, Error, WordFilters};
+use crate::{Distribution, Error, WordFilters, WordList};
 
 const DEFAULT_LIMIT: usize = 200;
 
-#[derive(Debug, Default)]
-pub struct Anagram {
-    settings: HashMap<String, String>,
+#[derive(Debug)]
+pub struct Anagram<'a> {
     letters: Vec<char>,
-    word_source: String,
-    words: Vec<String>,
+    words: &'a WordList,
     solutions: Vec<String>,
-    distribution: HashMap<usize, i32>,
+    distribution: Distribution,
     limit: Option<usize>,
 }
 
-impl Anagram {
-    pub fn new(letters: &str, settings: HashMap<String, String>) -> Result<Self, Error> {
+impl<'a> Anagram<'a> {
+    pub fn new(letters: &str, words: &'a WordList) -> Result<Self, Error> {
         if letters.len() < 3 || letters.len() > 26 {
             return Err(Error::TooFewOrManyLetters(letters.len()));
         }
@@ -29,66 +27,19 @@ impl Anagram {
             .collect::<Vec<char>>();
 
         Ok(Self {
-            settings,
+            words,
             letters,
-            ..Default::default()
+            solutions: Vec::new(),
+            distribution: Distribution::new(),
+            limit: None,
         })
     }
 
-    pub fn set_word_source(&mut self, dir: Option<String>, file: Option<String>) -> &mut Self {
-        // Setup settings
-        let mut src_directory = self
-            .settings
-            .get("source_dir")
-            .map_or(DEFAULT_SOURCE_DIR, |v| v)
-            .to_string();
-        let mut src_file = self
-            .settings
-            .get("source_words_file")
-            .map_or(DEFAULT_WORDS_SOURCE_FILE, |v| v)
-            .to_string();
-
-        if let Some(sd) = dir {
-            src_directory = sd;
-        };
-        if let Some(sf) = file {
-            src_file = sf;
-        };
-
-        let src = format!("{}/{}", src_directory.clone(), src_file.clone());
-        println!("Using word list: {src}");
-        tracing::info!("Using word list: {}", src);
-
-        self.word_source = src;
-
-        self
-    }
-
-    pub fn load_words(&mut self) -> &mut Self {
-        let mut words = Vec::new();
-
-        for line in std::fs::read_to_string(&self.word_source)
-            .expect("Failed to read words file")
-            .lines()
-        {
-            if !line.is_empty() {
-                let ws = line.split_whitespace();
-                for w in ws {
-                    words.push(w.to_string());
-                }
-            }
-        }
-
-        self.words = words;
-
-        self
-    }
-
     #[tracing::instrument(skip(self))]
     pub fn find_solutions(&mut self) -> Result<&mut Self, Error> {
         tracing::trace!("{}", self.letters.clone().iter().collect::<String>());
 
-        let mut filtered = self.words.clone();
+        let mut filtered = self.words.to_vec();
 
         if self.letters.contains(&' ') {
             tracing::trace!("Found a space in letters");
@@ -128,28 +79,17 @@ impl Anagram {
     }
 
     pub fn count_solution(&mut self, chain_length: usize) -> &mut Self {
-        if let Some(count) = self.distribution.get(&chain_length) {
-            let v = count + 1;
-            self.distribution.insert(chain_length, v);
-        } else {
-            self.distribution.insert(chain_length, 1);
-        }
-
+        self.distribution.count(chain_length);
         self
     }
 
     pub fn word_source_string(&self) -> String {
-        let s1 = "Using words sourced from ".light_cyan().dim().to_string();
-        let s2 = self.word_source.clone().light_cyan().bold().to_string();
-        format!("{s1}{s2}")
+        self.words.source_string()
     }
 
     pub fn distribution_string(&self) -> String {
         let mut s = String::new();
-        let mut distributions = self.distribution.iter().collect::<Vec<_>>();
-        distributions.sort_by_key(|a| a.0);
-
-        for d in distributions {
+        for d in self.distribution.lengths() {
             s.push_str(&format!(
                 "  - {:3.0} solutions with {:2.0} characters\n",
                 d.1, d.0
@@ -188,7 +128,7 @@ impl Anagram {
         s.push_str(
             &format!(
                 "{} Solutions with {} letters.",
-                self.distribution.get(&word_length).unwrap_or(&0),
+                self.distribution.get(word_length),
                 word_length
             )
             .underlined()
@@ -204,7 +144,7 @@ impl Anagram {
                 s.push_str(
                     &format!(
                         "{} Solutions with {} letters.",
-                        self.distribution.get(&word_length).unwrap_or(&0),
+                        self.distribution.get(word_length),
                         word_length
                     )
                     .underlined()
@@ -0,0 +1,436 @@
+use std::{collections::HashMap, path::PathBuf};
+
+use colorful::Colorful;
+
+use crate::{DEFAULT_BEE_DIR, Distribution, Error, WordFilters, WordList};
+
+const ALPHABET: &str = "abcdefghijklmnopqrstuvwxyz";
+const MINIMUM_WORD_LENGTH: usize = 4;
+const PANGRAM_BONUS: usize = 7;
+
+/// Ranks and the percentage of the total score needed to reach them.
+pub const RANKS: [(&str, usize); 10] = [
+    ("Beginner", 0),
+    ("Good Start", 2),
+    ("Moving Up", 5),
+    ("Good", 8),
+    ("Solid", 15),
+    ("Nice", 25),
+    ("Great", 40),
+    ("Amazing", 50),
+    ("Genius", 70),
+    ("Queen Bee", 100),
+];
+
+/// Points for a word: one for four letters, a point a letter for longer
+/// words, and a bonus for a pangram.
+pub fn bee_score(word: &str, letters: &[char]) -> usize {
+    let score = if word.len() == MINIMUM_WORD_LENGTH {
+        1
+    } else {
+        word.len()
+    };
+    if letters.iter().all(|l| word.contains(*l)) {
+        score + PANGRAM_BONUS
+    } else {
+        score
+    }
+}
+
+/// A Spelling Bee puzzle and the words found so far, kept in a file per
+/// puzzle so that progress carries over between runs.
+#[derive(Debug)]
+pub struct Bee<'a> {
+    settings: HashMap<String, String>,
+    letters: Vec<char>,
+    words: &'a WordList,
+    state_file: PathBuf,
+    answers: Vec<String>,
+    found: Vec<String>,
+}
+
+impl<'a> Bee<'a> {
+    /// Start from the seven letters with the centre letter first.
+    pub fn new(
+        letters: &str,
+        words: &'a WordList,
+        settings: HashMap<String, String>,
+    ) -> Result<Self, Error> {
+        let letters = letters.to_ascii_lowercase().chars().collect::<Vec<char>>();
+        let mut distinct = letters.clone();
+        distinct.sort();
+        distinct.dedup();
+        if letters.len() != 7
+            || distinct.len() != 7
+            || !letters.iter().all(char::is_ascii_lowercase)
+        {
+            return Err(Error::BeeLetters(letters.iter().collect()));
+        }
+
+        let mut bee = Self {
+            settings,
+            words,
+            letters,
+            state_file: PathBuf::new(),
+            answers: Vec::new(),
+            found: Vec::new(),
+        };
+        bee.set_state_dir(None);
+        Ok(bee)
+    }
+
+    /// Directory holding the found words for each puzzle, from the `bee_dir`
+    /// setting unless given.
+    pub fn set_state_dir(&mut self, dir: Option<String>) -> &mut Self {
+        let dir = dir.unwrap_or(
+            self.settings
+                .get("bee_dir")
+                .map_or(DEFAULT_BEE_DIR, |v| v)
+                .to_string(),
+        );
+        self.state_file = PathBuf::from(dir).join(format!("{}.txt", self.puzzle_id()));
+        tracing::info!("Using bee state: {}", self.state_file.display());
+        self
+    }
+
+    /// The centre letter followed by the other six in order, so that the same
+    /// puzzle is recognised however its outer letters are typed.
+    pub fn puzzle_id(&self) -> String {
+        let mut outer = self.letters[1..].to_vec();
+        outer.sort();
+        std::iter::once(self.letters[0]).chain(outer).collect()
+    }
+
+    pub fn answers(&self) -> &[String] {
+        &self.answers
+    }
+
+    pub fn found(&self) -> &[String] {
+        &self.found
+    }
+
+    /// Answers that have not been found yet.
+    pub fn remaining(&self) -> Vec<&String> {
+        self.answers
+            .iter()
+            .filter(|w| !self.found.contains(w))
+            .collect()
+    }
+
+    /// Find the words of four or more letters that use the centre letter and
+    /// no letters from outside the puzzle.
+    #[tracing::instrument(skip(self))]
+    pub fn find_solutions(&mut self) -> Result<&mut Self, Error> {
+        let excluded = ALPHABET
+            .chars()
+            .filter(|c| !self.letters.contains(c))
+            .collect::<String>();
+
+        let mut answers = self
+            .words
+            .excluding_letters(&excluded)
+            .including_any_letters(&self.letters[0].to_string())
+            .iter()
+            .map(|w| w.to_lowercase())
+            .collect::<Vec<String>>()
+            .filter_to_minimum_length(MINIMUM_WORD_LENGTH);
+        answers.retain(|w| w.chars().all(|c| c.is_ascii_lowercase()));
+        answers.sort();
+        answers.dedup();
+        tracing::debug!("{} answers", answers.len());
+
+        if answers.is_empty() {
+            return Err(Error::NoWordFound);
+        }
+
+        self.answers = answers;
+        Ok(self)
+    }
+
+    /// Read the words found in earlier runs, if any.
+    pub fn load_found(&mut self) -> Result<&mut Self, Error> {
+        if !self.state_file.exists() {
+            return Ok(self);
+        }
+        let text = std::fs::read_to_string(&self.state_file)
+            .map_err(|e| Error::BeeState(format!("{}: {e}", self.state_file.display())))?;
+        self.found = text.split_whitespace().map(|w| w.to_string()).collect();
+        Ok(self)
+    }
+
+    /// Record newly found words. Every word must be one of the answers.
+    pub fn add_found(&mut self, words: &[String]) -> Result<&mut Self, Error> {
+        for word in words.iter().map(|w| w.to_lowercase()) {
+            if !self.answers.contains(&word) {
+                return Err(Error::BeeWord(word));
+            }
+            if !self.found.contains(&word) {
+                self.found.push(word);
+            }
+        }
+        self.found.sort();
+        Ok(self)
+    }
+
+    /// Forget the words found so far.
+    pub fn reset(&mut self) -> &mut Self {
+        self.found.clear();
+        self
+    }
+
+    /// Write the found words to the puzzle's file.
+    pub fn save_found(&mut self) -> Result<&mut Self, Error> {
+        let to_error =
+            |e: std::io::Error| Error::BeeState(format!("{}: {e}", self.state_file.display()));
+        if let Some(dir) = self.state_file.parent() {
+            std::fs::create_dir_all(dir).map_err(to_error)?;
+        }
+        let mut text = self.found.join("\n");
+        text.push('\n');
+        std::fs::write(&self.state_file, text).map_err(to_error)?;
+        Ok(self)
+    }
+
+    pub fn score(&self) -> usize {
+        self.found.iter().map(|w| bee_score(w, &self.letters)).sum()
+    }
+
+    pub fn max_score(&self) -> usize {
+        self.answers
+            .iter()
+            .map(|w| bee_score(w, &self.letters))
+            .sum()
+    }
+
+    /// Points needed for each rank, rounded to the nearest point.
+    pub fn thresholds(&self) -> Vec<(&'static str, usize)> {
+        let max = self.max_score();
+        RANKS
+            .iter()
+            .map(|(rank, percent)| (*rank, (max * percent + 50) / 100))
+            .collect()
+    }
+
+    /// The highest rank reached and the next rank with the points it needs.
+    pub fn rank(&self) -> (&'static str, Option<(&'static str, usize)>) {
+        let score = self.score();
+        let thresholds = self.thresholds();
+        let reached = thresholds
+            .iter()
+            .rposition(|(_, points)| score >= *points)
+            .unwrap_or(0);
+        (thresholds[reached].0, thresholds.get(reached + 1).copied())
+    }
+
+    /// Counts of the remaining words by first letter and length, the grid of
+    /// hints given alongside the puzzle.
+    pub fn hints(&self) -> Vec<(char, Distribution)> {
+        let mut letters = self.letters.clone();
+        letters.sort();
+        letters
+            .into_iter()
+            .map(|l| {
+                let distribution = self
+                    .remaining()
+                    .iter()
+                    .filter(|w| w.starts_with(l))
+                    .map(|w| w.len())
+                    .collect::<Distribution>();
+                (l, distribution)
+            })
+            .filter(|(_, d)| d.total() > 0)
+            .collect()
+    }
+
+    /// Counts of the remaining words by their first two letters.
+    pub fn two_letter_hints(&self) -> Vec<(String, usize)> {
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for word in self.remaining() {
+            *counts.entry(word[..2].to_string()).or_insert(0) += 1;
+        }
+        let mut counts = counts.into_iter().collect::<Vec<_>>();
+        counts.sort();
+        counts
+    }
+
+    pub fn word_source_string(&self) -> String {
+        self.words.source_string()
+    }
+
+    pub fn solutions_title(&self) -> String {
+        let intro = "Spelling Bee for ";
+        let mut ul = String::new();
+        for _ in 0..(intro.len() + self.letters.len()) {
+            ul.push('‾');
+        }
+
+        let puzzle = self.puzzle_id();
+        let summary = format!(
+            "{}{}{}",
+            intro.yellow().bold(),
+            puzzle[..1].to_ascii_uppercase().blue().bold(),
+            puzzle[1..].to_string().blue().bold()
+        );
+        format!("{}\n{}", summary, ul.bold().yellow())
+    }
+
+    pub fn progress_string(&self) -> String {
+        let mut s = String::new();
+        let score = self.score();
+        let (rank, next) = self.rank();
+
+        s.push_str("  ");
+        s.push_str(
+            &format!(
+                "{score} of {} points, {} of {} words. Rank: {rank}.",
+                self.max_score(),
+                self.found.len(),
+                self.answers.len()
+            )
+            .underlined()
+            .yellow()
+            .to_string(),
+        );
+        s.push_str("\n\n");
+        for (name, points) in self.thresholds() {
+            let marker = if name == rank { "»" } else { " " };
+            s.push_str(&format!("  {marker} {name:<10} {points:4}\n"));
+        }
+        if let Some((name, points)) = next {
+            s.push_str(&format!("\n  {} points to {name}\n", points - score));
+        }
+        s
+    }
+
+    pub fn found_string(&self) -> String {
+        let mut s = String::new();
+        s.push_str("  ");
+        s.push_str(&"Found words".underlined().yellow().to_string());
+        s.push_str("\n\n");
+        for word in self.found.iter() {
+            s.push_str(&format!("    {word}\n"));
+        }
+        s
+    }
+
+    pub fn hints_string(&self) -> String {
+        let remaining = self
+            .remaining()
+            .iter()
+            .map(|w| w.len())
+            .collect::<Distribution>();
+        let lengths = remaining
+            .lengths()
+            .iter()
+            .map(|(l, _)| *l)
+            .collect::<Vec<usize>>();
+
+        let mut s = String::new();
+        s.push_str("  ");
+        s.push_str(&"Words remaining".underlined().yellow().to_string());
+        s.push_str("\n\n");
+        for (length, count) in remaining.lengths() {
+            s.push_str(&format!(
+                "  - {count:3.0} words with {length:2.0} letters\n"
+            ));
+        }
+
+        s.push_str("\n     ");
+        for length in lengths.iter() {
+            s.push_str(&format!("{length:4}"));
+        }
+        s.push_str("   Σ\n");
+        for (letter, distribution) in self.hints() {
+            s.push_str(&format!("    {}", letter.to_ascii_uppercase()));
+            for length in lengths.iter() {
+                match distribution.get(*length) {
+                    0 => s.push_str("   -"),
+                    n => s.push_str(&format!("{n:4}")),
+                }
+            }
+            s.push_str(&format!("{:4}\n", distribution.total()));
+        }
+        s.push_str("    Σ");
+        for (_, count) in remaining.lengths() {
+            s.push_str(&format!("{count:4}"));
+        }
+        s.push_str(&format!("{:4}\n\n", remaining.total()));
+
+        let pairs = self
+            .two_letter_hints()
+            .iter()
+            .map(|(pair, count)| format!("{}-{count}", pair.to_ascii_uppercase()))
+            .collect::<Vec<String>>();
+        for line in pairs.chunks(8) {
+            s.push_str(&format!("    {}\n", line.join("  ")));
+        }
+        s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bee(words: &WordList) -> Bee<'_> {
+        let mut bee = Bee::new("gaelmnt", words, HashMap::new()).unwrap();
+        bee.find_solutions().unwrap();
+        bee
+    }
+
+    #[test]
+    fn test_answers_use_the_centre_letter() {
+        let words = WordList::from_words([
+            "glean", "angle", "tangle", "gentle", "lent", "mangle", "tangelm", "gleam", "angel",
+            "mental", "gmaletn", "lane", "gale",
+        ]);
+        let bee = bee(&words);
+
+        assert!(bee.answers().contains(&"gale".to_string()));
+        assert!(!bee.answers().contains(&"lent".to_string()));
+        assert!(!bee.answers().contains(&"lane".to_string()));
+        assert_eq!(bee.puzzle_id(), "gaelmnt");
+        assert_eq!(
+            Bee::new("gaelmng", &words, HashMap::new()).err(),
+            Some(Error::BeeLetters("gaelmng".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_score_and_rank() {
+        let words = WordList::from_words([
+            "glean", "angle", "tangle", "gentle", "lent", "mangle", "tangelm", "gleam", "angel",
+            "mental", "gmaletn", "lane", "gale",
+        ]);
+        let mut bee = bee(&words);
+        // gale 1, four five letter words 5 each, three six letter words 6
+        // each, and two pangrams of 7 + 7.
+        assert_eq!(bee.max_score(), 1 + 4 * 5 + 3 * 6 + 2 * 14);
+
+        bee.add_found(&["gale".to_string(), "GLEAN".to_string()])
+            .unwrap();
+        assert_eq!(bee.score(), 6);
+        assert_eq!(bee.rank(), ("Good", Some(("Solid", 10))));
+        assert_eq!(
+            bee.add_found(&["lent".to_string()]).err(),
+            Some(Error::BeeWord("lent".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_hints_count_remaining_words() {
+        let words = WordList::from_words([
+            "glean", "angle", "tangle", "gentle", "lent", "mangle", "tangelm", "gleam", "angel",
+            "mental", "gmaletn", "lane", "gale",
+        ]);
+        let mut bee = bee(&words);
+        bee.add_found(&["angle".to_string()]).unwrap();
+
+        let hints = bee.hints();
+        assert_eq!(hints[0].0, 'a');
+        assert_eq!(hints[0].1.lengths(), vec![(5, 1)]);
+        let g = hints.iter().find(|(l, _)| *l == 'g').unwrap();
+        assert_eq!(g.1.lengths(), vec![(4, 1), (5, 2), (6, 1), (7, 1)]);
+        assert!(bee.two_letter_hints().contains(&("gl".to_string(), 2)));
+    }
+}
@@ -0,0 +1,382 @@
+use std::collections::BTreeSet;
+
+use colorful::Colorful;
+
+use crate::{Distribution, Error, Trie, WordList};
+
+pub use generator::{BoardGenerator, DiceSet};
+
+mod generator;
+
+const DEFAULT_MINIMUM_LENGTH: usize = 3;
+
+/// Standard Boggle points for a word of the given length.
+pub fn word_score(length: usize) -> u32 {
+    match length {
+        0..=2 => 0,
+        3 | 4 => 1,
+        5 => 2,
+        6 => 3,
+        7 => 5,
+        _ => 11,
+    }
+}
+
+/// A rectangular grid of tiles. Each tile is a single letter except for the
+/// `q` tile which, by convention, reads as `qu`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Board {
+    rows: usize,
+    columns: usize,
+    tiles: Vec<String>,
+}
+
+impl Board {
+    /// Parse a grid given as rows separated by whitespace, `/` or `,`, for
+    /// example `abcd/efgh/ijkl/mnop`.
+    pub fn parse(grid: &str) -> Result<Self, Error> {
+        let rows = grid
+            .split(|c: char| c.is_whitespace() || c == '/' || c == ',')
+            .filter(|r| !r.is_empty())
+            .map(|r| r.to_ascii_lowercase())
+            .collect::<Vec<String>>();
+
+        let columns = rows.first().map_or(0, |r| r.chars().count());
+        if columns == 0
+            || rows.iter().any(|r| r.chars().count() != columns)
+            || rows
+                .iter()
+                .any(|r| !r.chars().all(|c| c.is_ascii_lowercase()))
+        {
+            return Err(Error::BoggleGrid(grid.to_string()));
+        }
+
+        let tiles = rows
+            .iter()
+            .flat_map(|r| r.chars())
+            .map(|c| {
+                if c == 'q' {
+                    "qu".to_string()
+                } else {
+                    c.to_string()
+                }
+            })
+            .collect::<Vec<String>>();
+
+        Ok(Self {
+            rows: rows.len(),
+            columns,
+            tiles,
+        })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn tiles(&self) -> &[String] {
+        &self.tiles
+    }
+
+    /// Cells touching the cell horizontally, vertically or diagonally.
+    pub fn neighbours(&self, cell: usize) -> Vec<usize> {
+        let row = (cell / self.columns) as isize;
+        let column = (cell % self.columns) as isize;
+
+        let mut neighbours = Vec::new();
+        for dr in -1..=1 {
+            for dc in -1..=1 {
+                if dr == 0 && dc == 0 {
+                    continue;
+                }
+                let r = row + dr;
+                let c = column + dc;
+                if r >= 0 && c >= 0 && (r as usize) < self.rows && (c as usize) < self.columns {
+                    neighbours.push(r as usize * self.columns + c as usize);
+                }
+            }
+        }
+        neighbours
+    }
+
+    pub fn grid_string(&self) -> String {
+        let mut s = String::new();
+        for row in self.tiles.chunks(self.columns) {
+            let row = row
+                .iter()
+                .map(|tile| {
+                    let tile = if tile == "qu" {
+                        "Qu".to_string()
+                    } else {
+                        tile.to_ascii_uppercase()
+                    };
+                    format!("{tile:<3}")
+                })
+                .collect::<String>();
+            s.push_str(&format!("    {}\n", row.trim_end()));
+        }
+        s
+    }
+}
+
+/// A trie of the words in lower case, for tracing paths through the board.
+pub(crate) fn board_index(words: &WordList) -> Trie {
+    Trie::from_words(words.iter().map(|w| w.to_lowercase()))
+}
+
+/// Find every word in the trie that can be traced through adjacent cells of
+/// the board without visiting a cell twice.
+pub(crate) fn find_words(board: &Board, index: &Trie, minimum: usize) -> Vec<String> {
+    let mut found = BTreeSet::new();
+    let mut visited = vec![false; board.tiles.len()];
+    let mut path = String::new();
+
+    for cell in 0..board.tiles.len() {
+        trace_path(
+            board,
+            index,
+            minimum,
+            cell,
+            index.root(),
+            &mut visited,
+            &mut path,
+            &mut found,
+        );
+    }
+
+    found.into_iter().collect()
+}
+
+#[allow(clippy::too_many_arguments)]
+fn trace_path(
+    board: &Board,
+    index: &Trie,
+    minimum: usize,
+    cell: usize,
+    node: usize,
+    visited: &mut [bool],
+    path: &mut String,
+    found: &mut BTreeSet<String>,
+) {
+    // Stop as soon as no word starts with the path.
+    let Some(node) = index.walk(node, &board.tiles[cell]) else {
+        return;
+    };
+    let length = path.len();
+    path.push_str(&board.tiles[cell]);
+
+    if path.len() >= minimum && index.is_word(node) {
+        found.insert(path.clone());
+    }
+    visited[cell] = true;
+    for next in board.neighbours(cell) {
+        if !visited[next] {
+            trace_path(board, index, minimum, next, node, visited, path, found);
+        }
+    }
+    visited[cell] = false;
+
+    path.truncate(length);
+}
+
+#[derive(Debug)]
+pub struct Boggle<'a> {
+    board: Board,
+    words: &'a WordList,
+    minimum: usize,
+    solutions: Vec<String>,
+    distribution: Distribution,
+}
+
+impl<'a> Boggle<'a> {
+    pub fn new(grid: &str, words: &'a WordList) -> Result<Self, Error> {
+        let board = Board::parse(grid)?;
+
+        Ok(Self {
+            words,
+            board,
+            minimum: DEFAULT_MINIMUM_LENGTH,
+            solutions: Vec::new(),
+            distribution: Distribution::new(),
+        })
+    }
+
+    pub fn set_minimum(&mut self, value: usize) -> &mut Self {
+        self.minimum = value;
+        self
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn find_solutions(&mut self) -> Result<&mut Self, Error> {
+        let index = board_index(self.words);
+        let mut found = find_words(&self.board, &index, self.minimum);
+        tracing::debug!("{} words found", found.len());
+
+        found.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        for word in found.iter() {
+            self.count_solution(word.len());
+        }
+
+        self.solutions = found;
+
+        Ok(self)
+    }
+
+    pub fn solutions(&self) -> &[String] {
+        &self.solutions
+    }
+
+    pub fn score(&self) -> u32 {
+        self.solutions.iter().map(|w| word_score(w.len())).sum()
+    }
+
+    pub fn count_solution(&mut self, word_length: usize) -> &mut Self {
+        self.distribution.count(word_length);
+        self
+    }
+
+    pub fn word_source_string(&self) -> String {
+        self.words.source_string()
+    }
+
+    pub fn distribution_string(&self) -> String {
+        let mut s = String::new();
+        for d in self.distribution.lengths() {
+            s.push_str(&format!(
+                "  - {:3.0} solutions with {:2.0} letters\n",
+                d.1, d.0
+            ));
+        }
+        s.push_str(&format!(
+            "\n  {} words scoring {} points\n",
+            self.solutions.len(),
+            self.score()
+        ));
+        s
+    }
+
+    pub fn solutions_title(&self) -> String {
+        let intro = "Words in the grid";
+        let mut ul = String::new();
+        for _ in 0..intro.len() {
+            ul.push('‾');
+        }
+
+        format!(
+            "{}\n{}\n\n{}",
+            intro.yellow().bold(),
+            ul.bold().yellow(),
+            self.board.grid_string().blue().bold()
+        )
+    }
+
+    pub fn solutions_string(&self) -> String {
+        if self.solutions.is_empty() {
+            return "  No words found\n".to_string();
+        }
+
+        let mut s = String::new();
+        let mut word_length = self.solutions.first().map_or(0, |w| w.len());
+
+        s.push_str("  ");
+        s.push_str(
+            &format!(
+                "{} Solutions with {} letters.",
+                self.distribution.get(word_length),
+                word_length
+            )
+            .underlined()
+            .yellow()
+            .to_string(),
+        );
+        s.push_str("\n\n");
+
+        for solution in self.solutions.iter() {
+            if solution.len() != word_length {
+                word_length = solution.len();
+                s.push_str("\n  ");
+                s.push_str(
+                    &format!(
+                        "{} Solutions with {} letters.",
+                        self.distribution.get(word_length),
+                        word_length
+                    )
+                    .underlined()
+                    .yellow()
+                    .to_string(),
+                );
+                s.push_str("\n\n");
+            }
+            s.push_str(&format!("    {solution}\n"));
+        }
+        s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_board_with_qu_tile() {
+        let board = Board::parse("abq/DEF").unwrap();
+        assert_eq!(board.rows(), 2);
+        assert_eq!(board.columns(), 3);
+        assert_eq!(board.tiles()[2], "qu");
+        assert_eq!(board.tiles()[3], "d");
+
+        assert_eq!(
+            Board::parse("abc/de"),
+            Err(Error::BoggleGrid("abc/de".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let board = Board::parse("abc/def/ghi").unwrap();
+        assert_eq!(board.neighbours(0), vec![1, 3, 4]);
+        assert_eq!(board.neighbours(4).len(), 8);
+    }
+
+    #[test]
+    fn test_find_words_without_reusing_cells() {
+        // s e
+        // a t
+        let board = Board::parse("se/at").unwrap();
+        let index = Trie::from_words(["sat", "seat", "east", "teas", "test", "sees", "at"]);
+
+        let found = find_words(&board, &index, 3);
+        assert_eq!(found, vec!["east", "sat", "seat", "teas"]);
+    }
+
+    #[test]
+    fn test_qu_tile_in_words() {
+        let board = Board::parse("qi/tz").unwrap();
+        let index = Trie::from_words(["quit", "quiz", "qit"]);
+
+        let found = find_words(&board, &index, 3);
+        assert_eq!(found, vec!["quit", "quiz"]);
+    }
+
+    #[test]
+    fn test_solutions_longest_first() {
+        let words = WordList::from_words(["sat", "seat", "east", "teas", "test"]);
+        let mut boggle = Boggle::new("se/at", &words).unwrap();
+        boggle.find_solutions().unwrap();
+        assert_eq!(boggle.solutions(), vec!["east", "seat", "teas", "sat"]);
+
+        let mut boggle = Boggle::new("zz/zz", &words).unwrap();
+        boggle.find_solutions().unwrap();
+        assert_eq!(boggle.solutions_string(), "  No words found\n");
+    }
+
+    #[test]
+    fn test_word_score() {
+        let scores = (2..=9).map(word_score).collect::<Vec<u32>>();
+        assert_eq!(scores, vec![0, 1, 1, 2, 3, 5, 11, 11]);
+    }
+}
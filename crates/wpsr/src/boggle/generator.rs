@@ -0,0 +1,321 @@
+use std::{fmt::Display, str::FromStr};
+
+use colorful::Colorful;
+use rand::{
+    SeedableRng,
+    seq::{IndexedRandom, SliceRandom},
+};
+use rand_chacha::ChaCha20Rng;
+
+use super::{Board, board_index, find_words, word_score};
+use crate::{Error, WordList};
+
+const DICE_BOARD_SIZE: usize = 4;
+
+/// The sixteen dice of the original game.
+const CLASSIC_DICE: [&str; 16] = [
+    "aaciot", "abilty", "abjmoq", "acdemp", "acelrs", "adenvz", "ahmors", "biforx", "denosw",
+    "dknotu", "eefhiy", "egkluy", "egintv", "ehinps", "elpstu", "gilruw",
+];
+
+/// The sixteen dice of the current edition.
+const MODERN_DICE: [&str; 16] = [
+    "aaeegn", "abbjoo", "achops", "affkps", "aoottw", "cimotu", "deilrx", "delrvy", "distty",
+    "eeghnw", "eeinsu", "ehrtvw", "eiosst", "elrtty", "himnqu", "hlnnrz",
+];
+
+/// Relative frequency of letters in English text, per thousand letters.
+const LETTER_FREQUENCIES: [(char, u32); 26] = [
+    ('a', 82),
+    ('b', 15),
+    ('c', 28),
+    ('d', 43),
+    ('e', 127),
+    ('f', 22),
+    ('g', 20),
+    ('h', 61),
+    ('i', 70),
+    ('j', 2),
+    ('k', 8),
+    ('l', 40),
+    ('m', 24),
+    ('n', 67),
+    ('o', 75),
+    ('p', 19),
+    ('q', 1),
+    ('r', 60),
+    ('s', 63),
+    ('t', 91),
+    ('u', 28),
+    ('v', 10),
+    ('w', 24),
+    ('x', 2),
+    ('y', 20),
+    ('z', 1),
+];
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum DiceSet {
+    #[default]
+    Classic,
+    Modern,
+    Frequency,
+}
+
+impl FromStr for DiceSet {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "classic" => Ok(Self::Classic),
+            "modern" => Ok(Self::Modern),
+            "frequency" => Ok(Self::Frequency),
+            _ => Err(format!("Invalid dice set: {s}")),
+        }
+    }
+}
+
+impl Display for DiceSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Classic => write!(f, "Classic"),
+            Self::Modern => write!(f, "Modern"),
+            Self::Frequency => write!(f, "Frequency"),
+        }
+    }
+}
+
+impl DiceSet {
+    /// Roll a board, returning the rows of letters with `q` for the `qu` tile.
+    fn roll(&self, rows: usize, columns: usize, rng: &mut ChaCha20Rng) -> Vec<String> {
+        let letters = match self {
+            DiceSet::Classic | DiceSet::Modern => {
+                let mut dice = if self == &DiceSet::Classic {
+                    CLASSIC_DICE.to_vec()
+                } else {
+                    MODERN_DICE.to_vec()
+                };
+                dice.shuffle(rng);
+                dice.iter()
+                    .map(|die| {
+                        let faces = die.chars().collect::<Vec<char>>();
+                        *faces.choose(rng).unwrap()
+                    })
+                    .collect::<Vec<char>>()
+            }
+            DiceSet::Frequency => (0..rows * columns)
+                .map(|_| {
+                    LETTER_FREQUENCIES
+                        .choose_weighted(rng, |(_, weight)| *weight)
+                        .unwrap()
+                        .0
+                })
+                .collect::<Vec<char>>(),
+        };
+
+        letters
+            .chunks(columns)
+            .map(|row| row.iter().collect::<String>())
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+pub struct BoardGenerator<'a> {
+    dice: DiceSet,
+    rows: usize,
+    columns: usize,
+    words: &'a WordList,
+    minimum: usize,
+    min_words: usize,
+    min_score: u32,
+    attempts: usize,
+    seed: Option<u64>,
+    board: Option<Board>,
+    found: Vec<String>,
+    tried: usize,
+}
+
+impl<'a> BoardGenerator<'a> {
+    pub fn new(
+        dice: DiceSet,
+        rows: usize,
+        columns: usize,
+        words: &'a WordList,
+    ) -> Result<Self, Error> {
+        if dice != DiceSet::Frequency && (rows != DICE_BOARD_SIZE || columns != DICE_BOARD_SIZE) {
+            return Err(Error::BoggleDiceSize(rows, columns));
+        }
+        if rows == 0 || columns == 0 {
+            return Err(Error::BoggleGrid(format!("{rows}x{columns}")));
+        }
+
+        Ok(Self {
+            words,
+            dice,
+            rows,
+            columns,
+            minimum: 3,
+            attempts: 1000,
+            min_words: 0,
+            min_score: 0,
+            seed: None,
+            board: None,
+            found: Vec::new(),
+            tried: 0,
+        })
+    }
+
+    pub fn set_minimum(&mut self, value: usize) -> &mut Self {
+        self.minimum = value;
+        self
+    }
+
+    pub fn set_min_words(&mut self, value: usize) -> &mut Self {
+        self.min_words = value;
+        self
+    }
+
+    pub fn set_min_score(&mut self, value: u32) -> &mut Self {
+        self.min_score = value;
+        self
+    }
+
+    pub fn set_attempts(&mut self, value: usize) -> &mut Self {
+        self.attempts = value;
+        self
+    }
+
+    pub fn set_seed(&mut self, value: Option<u64>) -> &mut Self {
+        self.seed = value;
+        self
+    }
+
+    /// Roll boards until one has at least the minimum number of words and
+    /// reaches the minimum score.
+    #[tracing::instrument(skip(self))]
+    pub fn generate(&mut self) -> Result<&mut Self, Error> {
+        let mut rng = match self.seed {
+            Some(seed) => ChaCha20Rng::seed_from_u64(seed),
+            None => ChaCha20Rng::from_rng(&mut rand::rng()),
+        };
+        let index = board_index(self.words);
+
+        for attempt in 1..=self.attempts {
+            let rows = self.dice.roll(self.rows, self.columns, &mut rng);
+            let board = Board::parse(&rows.join("/"))?;
+            let found = find_words(&board, &index, self.minimum);
+            let score = found.iter().map(|w| word_score(w.len())).sum::<u32>();
+            tracing::debug!(
+                "Attempt {attempt}: {} with {} words scoring {score}",
+                rows.join("/"),
+                found.len()
+            );
+
+            if found.len() >= self.min_words && score >= self.min_score {
+                self.board = Some(board);
+                self.found = found;
+                self.tried = attempt;
+                return Ok(self);
+            }
+        }
+
+        Err(Error::BoggleBoardNotFound(self.attempts))
+    }
+
+    pub fn board(&self) -> Option<&Board> {
+        self.board.as_ref()
+    }
+
+    pub fn score(&self) -> u32 {
+        self.found.iter().map(|w| word_score(w.len())).sum()
+    }
+
+    /// The generated grid in the form accepted by `boggle solve`.
+    pub fn grid(&self) -> String {
+        let Some(board) = &self.board else {
+            return String::new();
+        };
+        board
+            .tiles()
+            .chunks(board.columns())
+            .map(|row| row.iter().map(|t| &t[..1]).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("/")
+    }
+
+    pub fn word_source_string(&self) -> String {
+        self.words.source_string()
+    }
+
+    pub fn board_title(&self) -> String {
+        let intro = format!("{} board ", self.dice);
+        let grid = self.grid();
+        let mut ul = String::new();
+        for _ in 0..(intro.len() + grid.len()) {
+            ul.push('‾');
+        }
+
+        let summary = format!("{}{}", intro.yellow().bold(), grid.blue().bold());
+        format!("{}\n{}", summary, ul.bold().yellow())
+    }
+
+    pub fn board_string(&self) -> String {
+        let Some(board) = &self.board else {
+            return String::new();
+        };
+        format!(
+            "{}\n  {} words scoring {} points, found after {} attempts\n",
+            board.grid_string(),
+            self.found.len(),
+            self.score(),
+            self.tried
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dice_sets_need_four_by_four() {
+        let words = WordList::default();
+        assert_eq!(
+            BoardGenerator::new(DiceSet::Classic, 5, 5, &words).err(),
+            Some(Error::BoggleDiceSize(5, 5))
+        );
+        assert!(BoardGenerator::new(DiceSet::Frequency, 5, 5, &words).is_ok());
+    }
+
+    #[test]
+    fn test_roll_uses_dice_faces() {
+        let mut rng = ChaCha20Rng::seed_from_u64(7);
+        let rows = DiceSet::Modern.roll(4, 4, &mut rng);
+        assert_eq!(rows.len(), 4);
+        assert!(rows.iter().all(|r| r.len() == 4));
+        assert!(
+            rows.concat()
+                .chars()
+                .all(|c| MODERN_DICE.iter().any(|d| d.contains(c)))
+        );
+    }
+
+    #[test]
+    fn test_generate_rejects_sparse_boards() {
+        let words = WordList::from_words(["tea", "eat", "ate", "sea", "set", "tes"]);
+
+        let mut generator = BoardGenerator::new(DiceSet::Frequency, 3, 3, &words).unwrap();
+        generator
+            .set_seed(Some(1))
+            .set_attempts(20)
+            .set_min_words(100);
+        assert_eq!(
+            generator.generate().err(),
+            Some(Error::BoggleBoardNotFound(20))
+        );
+
+        generator.set_min_words(0);
+        assert!(generator.generate().is_ok());
+        assert_eq!(generator.grid().len(), 11);
+    }
+}
@@ -1,5 +1,7 @@
+mod archive;
 mod shape;
 mod solution;
 
+pub use archive::{Archive, ArchiveEntry, today};
 pub use shape::Shape;
 pub use solution::{LettersBoxed, Shuffle, Solution};
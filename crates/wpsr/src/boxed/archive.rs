@@ -0,0 +1,264 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use colorful::Colorful;
+use serde::{Deserialize, Serialize};
+
+use super::{Shuffle, Solution};
+use crate::{DEFAULT_BOXED_ARCHIVE_DIR, Error, WordList};
+
+/// A boxed puzzle as it was solved or generated, with everything needed to
+/// run the solver again and compare the result.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArchiveEntry {
+    pub letters: String,
+    pub date: String,
+    pub command: String,
+    pub seed: u64,
+    pub word_source: String,
+    pub max_chain: usize,
+    pub shuffle_depth: i8,
+    pub shuffle: String,
+    pub random_solutions: usize,
+    pub best_solution: Option<String>,
+    pub solution_count: usize,
+    /// The `--min-frequency` the word list was narrowed with, if any.
+    #[serde(default)]
+    pub min_frequency: Option<u32>,
+    /// True when the word list was put in order of `--common` use.
+    #[serde(default)]
+    pub common: bool,
+    /// True when proper nouns and acronyms were left in the word list.
+    #[serde(default)]
+    pub include_proper_nouns: bool,
+}
+
+impl ArchiveEntry {
+    /// Record the outcome of a solver run made by the given command.
+    pub fn new(
+        command: &str,
+        solution: &Solution,
+        max_chain: usize,
+        shuffle_depth: i8,
+        shuffle: &Shuffle,
+        random_solutions: usize,
+    ) -> Self {
+        Self {
+            letters: solution.letters(),
+            date: today(),
+            command: command.to_string(),
+            seed: solution.seed(),
+            word_source: solution.word_source().to_string(),
+            max_chain,
+            shuffle_depth,
+            shuffle: shuffle.to_string(),
+            random_solutions,
+            best_solution: solution.best_solution().cloned(),
+            solution_count: solution.solutions().len(),
+            ..Default::default()
+        }
+    }
+
+    /// The name of the entry's file, without the extension.
+    pub fn id(&self) -> String {
+        format!("{}-{}-{}", self.date, self.letters, self.command)
+    }
+
+    /// Run the solver again with the recorded letters, settings and seed over
+    /// the given word list, the same way as the command that recorded it.
+    pub fn replay<'a>(&self, words: &'a WordList) -> Result<Solution<'a>, Error> {
+        let mut solution = Solution::new(&self.letters, words)?;
+        solution
+            .set_max_chain(self.max_chain)
+            .set_shuffle_depth(self.shuffle_depth)
+            .set_seed(Some(self.seed));
+        if self.command == "solve" {
+            solution.find_random_solution(self.shuffle.parse().unwrap_or_default())?;
+        } else {
+            solution
+                .find_best_solution()?
+                .find_random_solutions(self.random_solutions, &indicatif::ProgressBar::hidden());
+        }
+        Ok(solution)
+    }
+
+    /// True when a replayed run found the same best solution and the same
+    /// number of solutions.
+    pub fn matches(&self, solution: &Solution) -> bool {
+        self.best_solution.as_ref() == solution.best_solution()
+            && self.solution_count == solution.solutions().len()
+    }
+}
+
+impl Display for ArchiveEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}  {:3} solutions  {}",
+            self.id(),
+            self.solution_count,
+            self.best_solution.as_deref().unwrap_or("-")
+        )
+    }
+}
+
+/// A directory of TOML files, one for each archived puzzle.
+#[derive(Debug, Default)]
+pub struct Archive {
+    dir: PathBuf,
+}
+
+impl Archive {
+    /// Open the archive in the given directory, or the `boxed_archive_dir`
+    /// setting when none is given.
+    pub fn new(dir: Option<String>, settings: &HashMap<String, String>) -> Self {
+        let dir = dir.unwrap_or(
+            settings
+                .get("boxed_archive_dir")
+                .map_or(DEFAULT_BOXED_ARCHIVE_DIR, |v| v)
+                .to_string(),
+        );
+        tracing::info!("Using boxed archive: {dir}");
+        Self {
+            dir: PathBuf::from(dir),
+        }
+    }
+
+    fn path(&self, id: &str) -> PathBuf {
+        self.dir.join(format!("{id}.toml"))
+    }
+
+    /// Write the entry, replacing any entry for the same puzzle made by the
+    /// same command on the same day.
+    pub fn record(&self, entry: &ArchiveEntry) -> Result<PathBuf, Error> {
+        let path = self.path(&entry.id());
+        let to_error = |e: String| Error::BoxedArchive(format!("{}: {e}", path.display()));
+
+        std::fs::create_dir_all(&self.dir).map_err(|e| to_error(e.to_string()))?;
+        let text = toml::to_string(entry).map_err(|e| to_error(e.to_string()))?;
+        std::fs::write(&path, text).map_err(|e| to_error(e.to_string()))?;
+        tracing::info!("Archived {}", path.display());
+        Ok(path)
+    }
+
+    /// Read the entry with the given id.
+    pub fn get(&self, id: &str) -> Result<ArchiveEntry, Error> {
+        let path = self.path(id);
+        if !path.exists() {
+            return Err(Error::BoxedArchiveEntry(id.to_string()));
+        }
+        let to_error = |e: String| Error::BoxedArchive(format!("{}: {e}", path.display()));
+
+        let text = std::fs::read_to_string(&path).map_err(|e| to_error(e.to_string()))?;
+        toml::from_str(&text).map_err(|e| to_error(e.to_string()))
+    }
+
+    /// Every entry in the archive, oldest first.
+    pub fn list(&self) -> Result<Vec<ArchiveEntry>, Error> {
+        if !self.dir.exists() {
+            return Ok(Vec::new());
+        }
+        let mut ids = std::fs::read_dir(&self.dir)
+            .map_err(|e| Error::BoxedArchive(format!("{}: {e}", self.dir.display())))?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.extension().is_some_and(|e| e == "toml"))
+            .filter_map(|path| path.file_stem().map(|s| s.to_string_lossy().to_string()))
+            .collect::<Vec<String>>();
+        ids.sort();
+
+        ids.iter().map(|id| self.get(id)).collect()
+    }
+
+    pub fn list_string(&self, entries: &[ArchiveEntry]) -> String {
+        let intro = "Boxed puzzle archive in ";
+        let dir = self.dir.display().to_string();
+        let mut ul = String::new();
+        for _ in 0..(intro.len() + dir.len()) {
+            ul.push('‾');
+        }
+
+        let mut s = format!(
+            "{}{}\n{}\n\n",
+            intro.yellow().bold(),
+            dir.blue().bold(),
+            ul.bold().yellow()
+        );
+        for entry in entries {
+            s.push_str(&format!("    {entry}\n"));
+        }
+        s
+    }
+}
+
+/// Today's date in UTC as `YYYY-MM-DD`.
+pub fn today() -> String {
+    let days = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() / 86_400);
+    civil_date(days as i64)
+}
+
+/// Convert days since 1970-01-01 to a calendar date, following Howard
+/// Hinnant's `civil_from_days`.
+fn civil_date(days: i64) -> String {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_civil_date() {
+        assert_eq!(civil_date(0), "1970-01-01");
+        assert_eq!(civil_date(11_016), "2000-02-29");
+        assert_eq!(civil_date(20_745), "2026-10-19");
+    }
+
+    #[test]
+    fn test_record_and_read_back() {
+        let dir = std::env::temp_dir().join(format!("wpsr-archive-{}", std::process::id()));
+        let archive = Archive::new(Some(dir.display().to_string()), &HashMap::new());
+        let entry = ArchiveEntry {
+            letters: "ouhimagplryf".to_string(),
+            date: "2026-10-19".to_string(),
+            command: "solve".to_string(),
+            seed: 42,
+            word_source: "words/default.slb".to_string(),
+            max_chain: 10,
+            shuffle_depth: 3,
+            shuffle: "None".to_string(),
+            random_solutions: 0,
+            best_solution: Some("hypo -> ogham -> morph".to_string()),
+            solution_count: 1,
+            min_frequency: Some(5),
+            common: true,
+            include_proper_nouns: false,
+        };
+
+        archive.record(&entry).unwrap();
+        assert_eq!(archive.get("2026-10-19-ouhimagplryf-solve").unwrap(), entry);
+        assert_eq!(archive.list().unwrap(), vec![entry]);
+        assert_eq!(
+            archive.get("2026-10-20-ouhimagplryf-solve").err(),
+            Some(Error::BoxedArchiveEntry(
+                "2026-10-20-ouhimagplryf-solve".to_string()
+            ))
+        );
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
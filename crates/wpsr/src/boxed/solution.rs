@@ -1,8 +1,9 @@
-use std::collections::HashMap;
-
 use colorful::Colorful;
+use indicatif::ProgressBar;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
 
-use crate::{DEFAULT_BOXED_SOURCE_FILE, DEFAULT_SOURCE_DIR, Error};
+use crate::{Distribution, Error, WordList};
 
 pub use letters_boxed::{LettersBoxed, Shuffle};
 
@@ -10,20 +11,20 @@ use super::Shape;
 
 mod letters_boxed;
 
-#[derive(Debug, Default)]
-pub struct Solution {
-    settings: HashMap<String, String>,
+#[derive(Debug)]
+pub struct Solution<'a> {
     letters: Vec<char>,
-    word_source: String,
-    words: Vec<String>,
+    words: &'a WordList,
     max_chain: usize,
     shuffle_depth: i8,
     solutions: Vec<String>,
-    distribution: HashMap<usize, i32>,
+    distribution: Distribution,
+    seed: u64,
+    rng: Option<ChaCha20Rng>,
 }
 
-impl Solution {
-    pub fn new(letters: &str, settings: HashMap<String, String>) -> Result<Self, Error> {
+impl<'a> Solution<'a> {
+    pub fn new(letters: &str, words: &'a WordList) -> Result<Self, Error> {
         if letters.len() < 9 || letters.len() > 24 {
             return Err(Error::TooFewOrManyLetters(letters.len()));
         }
@@ -37,81 +38,75 @@ impl Solution {
             .map(|l| l.to_ascii_lowercase())
             .collect::<Vec<char>>();
 
-        Ok(Self {
-            settings,
+        let mut solution = Self {
+            words,
             letters,
             max_chain: 10,
             shuffle_depth: 3,
-            ..Default::default()
-        })
-    }
-
-    pub fn set_word_source(&mut self, dir: Option<String>, file: Option<String>) -> &mut Self {
-        // Setup settings
-        let mut src_directory = self
-            .settings
-            .get("source_dir")
-            .map_or(DEFAULT_SOURCE_DIR, |v| v)
-            .to_string();
-        let mut src_file = self
-            .settings
-            .get("source_boxed_file")
-            .map_or(DEFAULT_BOXED_SOURCE_FILE, |v| v)
-            .to_string();
-
-        if let Some(sd) = dir {
-            src_directory = sd;
-        };
-        if let Some(sf) = file {
-            src_file = sf;
+            solutions: Vec::new(),
+            distribution: Distribution::new(),
+            seed: 0,
+            rng: None,
         };
+        solution.set_seed(None);
+        Ok(solution)
+    }
 
-        let src = format!("{}/{}", src_directory.clone(), src_file.clone());
-        tracing::info!("Using word list: {}", src);
+    pub fn set_max_chain(&mut self, value: usize) -> &mut Self {
+        self.max_chain = value;
+        self
+    }
 
-        self.word_source = src;
+    pub fn set_shuffle_depth(&mut self, value: i8) -> &mut Self {
+        self.shuffle_depth = value;
+        self
+    }
 
+    /// Seed the random choices made while building word chains, so that a
+    /// run can be repeated. A random seed is chosen when none is given.
+    pub fn set_seed(&mut self, value: Option<u64>) -> &mut Self {
+        self.seed = value.unwrap_or_else(rand::random);
+        self.rng = Some(ChaCha20Rng::seed_from_u64(self.seed));
         self
     }
 
-    pub fn load_words(&mut self) -> &mut Self {
-        let mut words = Vec::new();
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
 
-        for line in std::fs::read_to_string(&self.word_source)
-            .expect("Failed to read words file")
-            .lines()
-        {
-            if !line.is_empty() {
-                let ws = line.split_whitespace();
-                for w in ws {
-                    words.push(w.to_string());
-                }
-            }
-        }
+    pub fn letters(&self) -> String {
+        self.letters.iter().collect()
+    }
 
-        self.words = words;
+    pub fn word_source(&self) -> &str {
+        self.words.source()
+    }
 
-        self
+    pub fn solutions(&self) -> &[String] {
+        &self.solutions
     }
 
-    pub fn set_max_chain(&mut self, value: usize) -> &mut Self {
-        self.max_chain = value;
-        self
+    /// The first of the solutions with the fewest words.
+    pub fn best_solution(&self) -> Option<&String> {
+        self.solutions
+            .iter()
+            .min_by_key(|s| s.chars().filter(|c| *c == '>').count())
     }
 
-    pub fn set_shuffle_depth(&mut self, value: i8) -> &mut Self {
-        self.shuffle_depth = value;
-        self
+    fn next_seed(&mut self) -> u64 {
+        self.rng.as_mut().map_or(0, |rng| rng.next_u64())
     }
 
     pub fn find_best_solution(&mut self) -> Result<&mut Self, Error> {
         tracing::info!("Get un-shuffled word list");
         let mut shuffle = Shuffle::None;
-        let mut puzzle = LettersBoxed::new(&self.letters, &self.words);
+        let seed = self.next_seed();
+        let mut puzzle = LettersBoxed::new(&self.letters, self.words);
         match puzzle
             .filter_words_with_letters_only()
             .filter_exclude_invalid_pairs()
             .set_max_chain(self.max_chain)
+            .set_seed(seed)
             .build_word_chain(&mut shuffle)
         {
             Ok(_) => {
@@ -130,12 +125,14 @@ impl Solution {
     #[tracing::instrument(skip(self))]
     pub fn find_random_solution(&mut self, mut shuffle: Shuffle) -> Result<&mut Self, Error> {
         tracing::info!("Get un-shuffled word list");
-        let mut puzzle = LettersBoxed::new(&self.letters, &self.words);
+        let seed = self.next_seed();
+        let mut puzzle = LettersBoxed::new(&self.letters, self.words);
         match puzzle
             .filter_words_with_letters_only()
             .filter_exclude_invalid_pairs()
             .set_max_chain(self.max_chain)
             .set_shuffle_depth(self.shuffle_depth)
+            .set_seed(seed)
             .build_word_chain(&mut shuffle)
         {
             Ok(_) => {
@@ -156,17 +153,39 @@ impl Solution {
         Ok(self)
     }
 
-    pub fn count_solution(&mut self, chain_length: usize) -> &mut Self {
-        if let Some(count) = self.distribution.get(&chain_length) {
-            let v = count + 1;
-            self.distribution.insert(chain_length, v);
-        } else {
-            self.distribution.insert(chain_length, 1);
+    /// Look for up to `count` different random solutions, giving up after
+    /// ten attempts that only find solutions already seen.
+    pub fn find_random_solutions(&mut self, count: usize, bar: &ProgressBar) -> &mut Self {
+        let mut max_clashes = 10;
+        let mut max_solutions = count;
+
+        while max_solutions > 0 && max_clashes > 0 {
+            tracing::info!(
+                "Generating random solutions max_solutions={max_solutions} and max_clashes={max_clashes}"
+            );
+            match self.find_random_solution(Shuffle::Once) {
+                Ok(_) => {
+                    max_solutions -= 1;
+                    bar.inc(1);
+                }
+                Err(Error::SolutionAlreadyFound) => {
+                    max_clashes -= 1;
+                }
+                Err(e) => {
+                    tracing::error!("Failed to build word chain: {}", e);
+                    max_solutions -= 1;
+                }
+            };
         }
 
         self
     }
 
+    pub fn count_solution(&mut self, chain_length: usize) -> &mut Self {
+        self.distribution.count(chain_length);
+        self
+    }
+
     pub fn shape_len(&self) -> usize {
         match Shape::from_edges((self.letters.len() / 3) as u8) {
             Ok(shape) => shape.to_string().len(),
@@ -182,17 +201,12 @@ impl Solution {
     }
 
     pub fn word_source_string(&self) -> String {
-        let s1 = "Using words sourced from ".light_cyan().dim().to_string();
-        let s2 = self.word_source.clone().light_cyan().bold().to_string();
-        format!("{s1}{s2}")
+        self.words.source_string()
     }
 
     pub fn distribution_string(&self) -> String {
         let mut s = String::new();
-        let mut distributions = self.distribution.iter().collect::<Vec<_>>();
-        distributions.sort_by_key(|a| a.0);
-
-        for d in distributions {
+        for d in self.distribution.lengths() {
             s.push_str(&format!(
                 "  - {:3.0} solutions with {:2.0} words\n",
                 d.1, d.0
@@ -242,7 +256,7 @@ impl Solution {
         s.push_str(
             &format!(
                 "{} Solutions with {} words.",
-                self.distribution.get(&word_length).unwrap_or(&0),
+                self.distribution.get(word_length),
                 word_length
             )
             .underlined()
@@ -258,7 +272,7 @@ impl Solution {
                 s.push_str(
                     &format!(
                         "{} Solutions with {} words.",
-                        self.distribution.get(&word_length).unwrap_or(&0),
+                        self.distribution.get(word_length),
                         word_length
                     )
                     .underlined()
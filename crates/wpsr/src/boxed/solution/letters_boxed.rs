@@ -1,4 +1,4 @@
-use std::collections::VecDeque;
+use std::{borrow::Cow, collections::VecDeque};
 
 mod edge;
 mod shuffle;
@@ -10,20 +10,21 @@ use rand_chacha::ChaCha20Rng;
 pub use shuffle::Shuffle;
 use weighted_word::WeightedWord;
 
-use crate::Error;
+use crate::{Error, Trie, WordList};
 
 #[derive(Debug)]
-pub struct LettersBoxed {
+pub struct LettersBoxed<'a> {
     letters: Vec<char>,
-    words: Vec<String>,
+    words: Cow<'a, WordList>,
     invalid_pairs: Vec<(char, char)>,
     word_chain: Vec<String>,
     edges: Vec<Edge>,
     max_chain: Option<usize>,
     shuffle_depth: Option<i8>,
+    seed: Option<u64>,
 }
 
-impl Default for LettersBoxed {
+impl Default for LettersBoxed<'_> {
     fn default() -> Self {
         let letters = vec!['o', 'u', 'h', 'i', 'm', 'a', 'g', 'p', 'l', 'r', 'y', 'f'];
         let edges = vec![
@@ -34,26 +35,26 @@ impl Default for LettersBoxed {
         ];
         Self {
             letters,
-            words: Vec::new(),
+            words: Cow::Owned(WordList::default()),
             invalid_pairs: Vec::new(),
             word_chain: Vec::new(),
             edges,
             max_chain: None,
             shuffle_depth: None,
+            seed: None,
         }
     }
 }
 
-impl LettersBoxed {
-    pub fn new(letters: &[char], words: &[String]) -> Self {
+impl<'a> LettersBoxed<'a> {
+    /// Borrow the word list until the words are filtered down to the puzzle.
+    pub fn new(letters: &[char], words: &'a WordList) -> Self {
         let mut s = Self::default();
         if !letters.is_empty() {
             s.letters = Vec::from(letters);
             s.generate_edges();
         }
-        if !words.is_empty() {
-            s.words = Vec::from(words);
-        }
+        s.words = Cow::Borrowed(words);
 
         s
     }
@@ -68,17 +69,19 @@ impl LettersBoxed {
         self
     }
 
+    pub fn set_seed(&mut self, value: u64) -> &mut Self {
+        self.seed = Some(value);
+        self
+    }
+
     #[tracing::instrument(skip(self))]
     pub fn filter_words_with_letters_only(&mut self) -> &mut Self {
-        let filtered = self
-            .words
-            .iter()
-            .filter(|word| word.chars().all(|c| self.letters.contains(&c)))
-            .map(|w| w.to_string())
-            .collect::<Vec<String>>();
+        self.words = Cow::Owned(
+            self.words
+                .only_letters(&self.letters.iter().collect::<String>()),
+        );
 
-        tracing::info!("Filtered to {} words", filtered.len());
-        self.words = filtered;
+        tracing::info!("Filtered to {} words", self.words.len());
         self
     }
 
@@ -125,27 +128,20 @@ impl LettersBoxed {
     pub fn filter_exclude_invalid_pairs(&mut self) -> &mut Self {
         self.generate_invalid_pairs();
 
-        let filtered = self
-            .words
-            .iter()
-            .filter(|word| {
-                let chars = word.chars().collect::<Vec<char>>();
-                let mut a = chars[0];
-                for b in chars.iter().skip(1) {
-                    if self.invalid_pairs.contains(&(a, *b))
-                        || self.invalid_pairs.contains(&(*b, a))
-                    {
-                        return false;
-                    }
-                    a = *b;
+        let filtered = self.words.filter(|word| {
+            let chars = word.chars().collect::<Vec<char>>();
+            let mut a = chars[0];
+            for b in chars.iter().skip(1) {
+                if self.invalid_pairs.contains(&(a, *b)) || self.invalid_pairs.contains(&(*b, a)) {
+                    return false;
                 }
-                true
-            })
-            .map(|w| w.to_string())
-            .collect::<Vec<String>>();
+                a = *b;
+            }
+            true
+        });
 
         tracing::info!("Filtered to {} words", filtered.len());
-        self.words = filtered;
+        self.words = Cow::Owned(filtered);
         self
     }
 
@@ -153,15 +149,18 @@ impl LettersBoxed {
     pub fn build_word_chain(&mut self, shuffle: &mut Shuffle) -> Result<(), Error> {
         tracing::info!("Building word chain");
         // Get the first word from the list of words
-        let mut rng = ChaCha20Rng::from_rng(&mut rand::rng());
-        let all_words = self.words.clone();
-        let words_list = all_words.clone();
+        let mut rng = match self.seed {
+            Some(seed) => ChaCha20Rng::seed_from_u64(seed),
+            None => ChaCha20Rng::from_rng(&mut rand::rng()),
+        };
+        let index = Trie::from_words(self.words.iter());
+        let words_list = self.words.to_vec();
         let word_chain = Vec::new();
         let unused_letters = String::from_iter(self.letters.clone());
         let shuffle_depth = self.shuffle_depth.unwrap_or(-1);
 
         let word_chain = get_word(
-            all_words,
+            &index,
             words_list,
             word_chain,
             unused_letters,
@@ -188,7 +187,7 @@ impl LettersBoxed {
 
 #[allow(clippy::too_many_arguments)]
 #[tracing::instrument(skip(
-    all_words,
+    index,
     words_list,
     word_chain,
     unused_letters,
@@ -198,7 +197,7 @@ impl LettersBoxed {
     shuffle_depth
 ))]
 pub fn get_word(
-    all_words: Vec<String>,
+    index: &Trie,
     mut words_list: Vec<String>,
     mut word_chain: Vec<String>,
     mut unused_letters: String,
@@ -281,13 +280,8 @@ pub fn get_word(
             }
             let mut next_word_chain = word_chain.clone();
             let next_unused_letters = unused_letters.clone();
-            let next_all_words = all_words.clone();
             let last_letter = word.chars().last().unwrap();
-            let words_list = all_words
-                .iter()
-                .filter(|w| w.chars().next().unwrap() == last_letter)
-                .map(|w| w.to_string())
-                .collect::<Vec<String>>();
+            let words_list = index.words_with_prefix(&last_letter.to_string());
             if words.is_empty() {
                 return Err(Error::NoWordFound);
             }
@@ -296,7 +290,7 @@ pub fn get_word(
                 shuffle_depth -= 1;
             }
             match get_word(
-                next_all_words,
+                index,
                 words_list,
                 next_word_chain,
                 next_unused_letters,
@@ -351,11 +345,12 @@ mod tests {
             "baz".to_string(),
         ];
 
+        let words = WordList::from_words(words);
         let mut letters_boxed = LettersBoxed::new(&letters, &words);
         letters_boxed.filter_words_with_letters_only();
         assert_eq!(letters_boxed.words.len(), 2);
-        assert_eq!(letters_boxed.words[0], "hello".to_string());
-        assert_eq!(letters_boxed.words[1], "world".to_string());
+        assert_eq!(letters_boxed.words.word(0), "hello".to_string());
+        assert_eq!(letters_boxed.words.word(1), "world".to_string());
     }
 
     #[test]
@@ -376,6 +371,7 @@ mod tests {
             "fred".to_string(),
         ];
 
+        let words = WordList::from_words(words);
         let mut letters_boxed = LettersBoxed::new(&letters, &words);
 
         println!("{:#?}", letters_boxed.edges);
@@ -387,9 +383,9 @@ mod tests {
         println!("{:#?}", letters_boxed.words);
 
         assert_eq!(letters_boxed.words.len(), 3);
-        assert_eq!(letters_boxed.words[0], "world".to_string());
-        assert_eq!(letters_boxed.words[1], "game".to_string());
-        assert_eq!(letters_boxed.words[2], "waldo".to_string());
+        assert_eq!(letters_boxed.words.word(0), "world".to_string());
+        assert_eq!(letters_boxed.words.word(1), "game".to_string());
+        assert_eq!(letters_boxed.words.word(2), "waldo".to_string());
     }
 
     #[test]
@@ -3,11 +3,32 @@ use std::fmt::Display;
 use clap::{Parser, Subcommand};
 use clap_verbosity_flag::Verbosity;
 
+pub(crate) use source::Source;
+pub(crate) use writer::Writer;
+
 mod alpha;
 mod anagram;
+mod bee;
+mod boggle;
 mod boxed;
+mod codeword;
+mod countdown;
+mod cryptogram;
+mod fill;
+mod hangman;
+mod jumble;
+mod ladder;
 mod list;
+mod lists;
+mod scrabble;
+mod source;
+mod twist;
+mod waffle;
+mod wheel;
+mod wordle;
 mod words;
+mod wordsearch;
+mod writer;
 
 #[derive(Parser, Debug)]
 #[clap(author, version, about, long_about = None)]
@@ -26,12 +47,44 @@ pub enum Commands {
     Alpha(alpha::Cmd),
     /// List available word lists
     List(list::Cmd),
+    /// Combine and compare word list files
+    Lists(lists::Cmd),
     /// Find words that are anagrams of a given letter string
     Anagram(anagram::Cmd),
+    /// Track Spelling Bee progress with hints for the words remaining
+    Bee(bee::Cmd),
+    /// Boggle word grid tools
+    Boggle(boggle::Cmd),
     /// Boxed word puzzle tools
     Boxed(boxed::Cmd),
+    /// Solve a codeword grid by propagating crossing letters
+    Codeword(codeword::Cmd),
+    /// Countdown letters and numbers round solvers
+    Countdown(countdown::Cmd),
+    /// Decrypt a substitution cipher using word patterns
+    Cryptogram(cryptogram::Cmd),
+    /// Fill a crossword grid template with words from the list
+    Fill(fill::Cmd),
+    /// Suggest the next hangman guess from the revealed pattern
+    Hangman(hangman::Cmd),
+    /// Unscramble a jumble and anagram the circled letters
+    Jumble(jumble::Cmd),
+    /// Find the shortest word ladders between two words
+    Ladder(ladder::Cmd),
+    /// Find and score Scrabble plays for a rack of tiles
+    Scrabble(scrabble::Cmd),
+    /// Find all words of three or more letters for a text twist round
+    Twist(twist::Cmd),
+    /// Solve a waffle grid and plan the fewest swaps
+    Waffle(waffle::Cmd),
+    /// Find the words in a nine letter word wheel
+    Wheel(wheel::Cmd),
+    /// Suggest guesses for one or more Wordle boards played together
+    Wordle(wordle::Cmd),
     /// Generate words from a string of letters
     Words(words::Cmd),
+    /// Word search puzzle tools
+    Wordsearch(wordsearch::Cmd),
 }
 
 impl Display for Commands {
@@ -39,9 +92,25 @@ impl Display for Commands {
         match self {
             Commands::Alpha(_) => write!(f, "alpha"),
             Commands::List(_) => write!(f, "list"),
+            Commands::Lists(_) => write!(f, "lists"),
             Commands::Anagram(_) => write!(f, "anagram"),
+            Commands::Bee(_) => write!(f, "bee"),
+            Commands::Boggle(_) => write!(f, "boggle"),
             Commands::Boxed(_) => write!(f, "boxed"),
+            Commands::Codeword(_) => write!(f, "codeword"),
+            Commands::Countdown(_) => write!(f, "countdown"),
+            Commands::Cryptogram(_) => write!(f, "cryptogram"),
+            Commands::Fill(_) => write!(f, "fill"),
+            Commands::Hangman(_) => write!(f, "hangman"),
+            Commands::Jumble(_) => write!(f, "jumble"),
+            Commands::Ladder(_) => write!(f, "ladder"),
+            Commands::Scrabble(_) => write!(f, "scrabble"),
+            Commands::Twist(_) => write!(f, "twist"),
+            Commands::Waffle(_) => write!(f, "waffle"),
+            Commands::Wheel(_) => write!(f, "wheel"),
+            Commands::Wordle(_) => write!(f, "wordle"),
             Commands::Words(_) => write!(f, "words"),
+            Commands::Wordsearch(_) => write!(f, "wordsearch"),
         }
     }
 }
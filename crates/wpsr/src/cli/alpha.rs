@@ -1,18 +1,12 @@
-use std::{
-    collections::{HashSet, VecDeque},
-    io::Write,
-    path::PathBuf,
-    str::FromStr,
-};
+use std::path::PathBuf;
 
 use clap::Parser;
 
-use crate::{Error, WordFilters};
+use crate::{Error, WordList, cli::Writer, word_list::parse_entries};
 
 const DEFAULT_SOURCE_DIR: &str = "words";
 const DEFAULT_SOURCE_FILE: &str = "wiki-100k.txt";
 const DEFAULT_OUTPUT_FILE: &str = "default.txt";
-const LINE_LENGTH: usize = 3010;
 
 #[derive(Parser, Debug, Clone)]
 pub struct Cmd {
@@ -25,6 +19,8 @@ pub struct Cmd {
     /// word list output file name
     #[arg(short, long)]
     pub output: Option<String>,
+    #[command(flatten)]
+    pub writer: Writer,
 }
 
 impl Cmd {
@@ -47,81 +43,42 @@ impl Cmd {
         };
 
         let src = format!("{}/{}", src_directory.clone(), src_file.clone());
-        let dest = format!(
+        let mut dest = PathBuf::from(format!(
             "{}/{}",
             src_directory.clone(),
-            self.output.unwrap_or(DEFAULT_OUTPUT_FILE.to_string())
-        );
+            self.output
+                .clone()
+                .unwrap_or(DEFAULT_OUTPUT_FILE.to_string())
+        ));
+        if self.output.is_none() {
+            dest.set_extension(self.writer.format.extension());
+        }
 
-        println!("Files: {src} and {dest}");
+        println!("Files: {src} and {}", dest.display());
 
-        let mut words = HashSet::new();
-        let mut count_duplicates = 0;
-
-        for line in std::fs::read_to_string(&src)
-            .expect("Failed to read words file")
-            .lines()
-        {
-            if !line.is_empty() {
-                let ws = line.split_whitespace();
-                for w in ws {
-                    if !words.insert(w.to_lowercase().to_string()) {
-                        count_duplicates += 1;
-                    };
-                }
-            }
-        }
+        let text = std::fs::read_to_string(&src)
+            .map_err(|e| Error::WordListRead(src.clone(), e.to_string()))?;
+        let entries = parse_entries(&text)?;
+        let total = entries.len();
+        let words = WordList::from_entries(entries).fold_case();
 
         println!(
-            "Found {} unique words after excluding {count_duplicates} ",
-            words.len()
+            "Found {} unique words after excluding {} ",
+            words.len(),
+            total - words.len()
         );
 
-        let words = words.into_iter().collect::<Vec<String>>();
-
         tracing::info!("Loaded {} words", words.len());
         const ALPHABET: &str = "abcdefghijklmnopqrstuvwxyz";
-        let mut words = words.filter_to_minimum_length(2);
-        words.filter_includes_only_letters(ALPHABET);
-
-        tracing::info!("Filtered words includes {} words", words.len());
-
-        write_words(words, &dest);
-
-        Ok(())
-    }
-}
-
-fn write_words(words: Vec<String>, src: &str) {
-    let lines = words_to_lines(words);
-
-    let path = PathBuf::from_str(src).unwrap();
-
-    let mut file = std::fs::File::create(path).unwrap();
-    for line in lines {
-        file.write_all(line.as_bytes()).unwrap();
-        file.write_all(b"\n").unwrap();
-    }
-}
+        let mut list = words
+            .filter(|w| w.chars().count() >= 2)
+            .only_letters(ALPHABET);
+        if list.has_frequencies() {
+            list = list.most_common_first()?;
+        }
 
-fn words_to_lines(words: Vec<String>) -> Vec<String> {
-    let mut lines = Vec::new();
-    let mut line = String::new();
-    let mut words = VecDeque::from(words);
+        tracing::info!("Filtered words includes {} words", list.len());
 
-    while !words.is_empty() {
-        let word = words.pop_front().unwrap();
-        if line.is_empty() {
-            line = word;
-            continue;
-        }
-        if line.len() + word.len() > LINE_LENGTH {
-            lines.push(line.clone());
-            line.clear();
-        }
-        line.push(' ');
-        line.push_str(&word);
+        self.writer.write(&list, &dest)
     }
-
-    lines
 }
@@ -1,17 +1,13 @@
 use std::collections::HashMap;
 
-use crate::{Anagram, Error};
+use crate::{Anagram, Error, cli::Source};
 use clap::Parser;
 
 #[derive(Parser, Debug, Clone)]
 pub struct Cmd {
     pub letters: String,
-    /// word list source directory
-    #[arg(short, long)]
-    pub dir: Option<String>,
-    /// word list source file
-    #[arg(short, long)]
-    pub file: Option<String>,
+    #[command(flatten)]
+    pub source: Source,
 }
 
 impl Cmd {
@@ -19,11 +15,9 @@ impl Cmd {
     pub fn run(&self, settings: HashMap<String, String>) -> Result<(), Error> {
         tracing::debug!("Args: {self:#?}");
 
-        let mut solution = Anagram::new(&self.letters, settings)?;
-        solution
-            .set_word_source(self.dir.clone(), self.file.clone())
-            .load_words()
-            .find_solutions()?;
+        let words = self.source.load(&settings)?;
+        let mut solution = Anagram::new(&self.letters, &words)?;
+        solution.find_solutions()?;
 
         println!("{}", solution.solutions_title());
         println!("{}\n", solution.word_source_string());
@@ -0,0 +1,54 @@
+use std::collections::HashMap;
+
+use crate::{Bee, Error, cli::Source};
+use clap::Parser;
+
+#[derive(Parser, Debug, Clone)]
+pub struct Cmd {
+    /// seven letters with the centre letter first
+    pub letters: String,
+    /// words found since the last run
+    pub found: Vec<String>,
+    #[command(flatten)]
+    pub source: Source,
+    /// directory holding the found words for each puzzle
+    #[arg(
+        short,
+        long,
+        long_help = "Directory holding the found words for each puzzle.\nDefaults to the `bee_dir` setting, or `.wpsr/bee` when that is not set."
+    )]
+    pub state: Option<String>,
+    /// forget the words found so far before adding any new ones
+    #[arg(long)]
+    pub reset: bool,
+    /// list the words found so far
+    #[arg(short, long)]
+    pub list: bool,
+}
+
+impl Cmd {
+    #[tracing::instrument(skip(self, settings))]
+    pub fn run(&self, settings: HashMap<String, String>) -> Result<(), Error> {
+        tracing::debug!("Args: {self:#?}");
+
+        let words = self.source.load(&settings)?;
+        let mut bee = Bee::new(&self.letters, &words, settings)?;
+        bee.set_state_dir(self.state.clone())
+            .find_solutions()?
+            .load_found()?;
+        if self.reset {
+            bee.reset();
+        }
+        bee.add_found(&self.found)?.save_found()?;
+
+        println!("{}", bee.solutions_title());
+        println!("{}\n", bee.word_source_string());
+        println!("{}", bee.progress_string());
+        if self.list {
+            println!("{}", bee.found_string());
+        }
+        println!("{}", bee.hints_string());
+
+        Ok(())
+    }
+}
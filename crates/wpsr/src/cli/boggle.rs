@@ -0,0 +1,46 @@
+use std::{collections::HashMap, fmt::Display};
+
+use clap::{Parser, Subcommand};
+use clap_verbosity_flag::Verbosity;
+
+use crate::Error;
+
+mod generate;
+mod solve;
+
+#[derive(Parser, Debug, Clone)]
+#[clap(author, version, about, long_about = None)]
+pub struct Cmd {
+    /// logging level
+    #[clap(flatten)]
+    pub logging: Verbosity,
+    /// Commands to run
+    #[command(subcommand)]
+    pub cmd: Commands,
+}
+
+#[derive(Debug, Subcommand, Clone)]
+pub enum Commands {
+    /// Generate a random board with a minimum word density
+    Generate(generate::Cmd),
+    /// Find all words in a grid
+    Solve(solve::Cmd),
+}
+
+impl Display for Commands {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Commands::Generate(_) => write!(f, "generate"),
+            Commands::Solve(_) => write!(f, "solve"),
+        }
+    }
+}
+
+impl Cmd {
+    pub fn run(self, settings: HashMap<String, String>) -> Result<(), Error> {
+        match self.cmd {
+            Commands::Generate(generate) => generate.run(settings),
+            Commands::Solve(solve) => solve.run(settings),
+        }
+    }
+}
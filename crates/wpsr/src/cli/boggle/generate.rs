@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+
+use clap::Parser;
+
+use crate::{BoardGenerator, DiceSet, Error, cli::Source};
+
+#[derive(Parser, Debug, Clone)]
+pub struct Cmd {
+    #[command(flatten)]
+    pub source: Source,
+    /// Dice set
+    #[arg(
+        short,
+        long,
+        default_value_t = DiceSet::Classic,
+        long_help = "Dice set\n\nClassic - The dice of the original game (4x4 only)\nModern - The dice of the current edition (4x4 only)\nFrequency - Letters drawn by their frequency in English"
+    )]
+    pub set: DiceSet,
+    /// number of rows in the board
+    #[arg(short, long, default_value_t = 4)]
+    pub rows: usize,
+    /// number of columns in the board
+    #[arg(short, long, default_value_t = 4)]
+    pub columns: usize,
+    /// minimum word length
+    #[arg(short, long, default_value_t = 3)]
+    pub minimum: usize,
+    /// minimum number of words the board must contain
+    #[arg(short = 'w', long, default_value_t = 50)]
+    pub min_words: usize,
+    /// minimum total score the board must reach
+    #[arg(short = 'p', long, default_value_t = 0)]
+    pub min_score: u32,
+    /// maximum number of boards to try
+    #[arg(short, long, default_value_t = 1000)]
+    pub attempts: usize,
+    /// seed for the random number generator
+    #[arg(long)]
+    pub seed: Option<u64>,
+    /// Bare result listing the grid for the puzzle only
+    #[arg(short, long)]
+    pub bare: bool,
+}
+
+impl Cmd {
+    #[tracing::instrument(skip(self, settings))]
+    pub fn run(self, settings: HashMap<String, String>) -> Result<(), Error> {
+        tracing::debug!("Args: {self:#?}");
+
+        let words = self.source.load(&settings)?;
+        let mut generator = BoardGenerator::new(self.set, self.rows, self.columns, &words)?;
+        generator
+            .set_minimum(self.minimum)
+            .set_min_words(self.min_words)
+            .set_min_score(self.min_score)
+            .set_attempts(self.attempts)
+            .set_seed(self.seed)
+            .generate()?;
+
+        if self.bare {
+            println!("{}", generator.grid());
+        } else {
+            println!("{}", generator.board_title());
+            println!("{}\n", generator.word_source_string());
+            println!("{}", generator.board_string());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+use std::collections::HashMap;
+
+use clap::Parser;
+
+use crate::{Boggle, Error, cli::Source};
+
+#[derive(Parser, Debug, Clone)]
+pub struct Cmd {
+    /// grid rows separated by `/`, with `q` standing for the `qu` tile
+    pub grid: String,
+    #[command(flatten)]
+    pub source: Source,
+    /// minimum word length
+    #[arg(short, long, default_value_t = 3)]
+    pub minimum: usize,
+}
+
+impl Cmd {
+    pub fn run(self, settings: HashMap<String, String>) -> Result<(), Error> {
+        tracing::debug!("Args: {self:#?}");
+
+        let words = self.source.load(&settings)?;
+        let mut solution = Boggle::new(&self.grid, &words)?;
+        solution.set_minimum(self.minimum).find_solutions()?;
+
+        println!("{}", solution.solutions_title());
+        println!("{}\n", solution.word_source_string());
+        println!("{}", solution.distribution_string());
+        println!("{}", solution.solutions_string());
+
+        Ok(())
+    }
+}
@@ -5,6 +5,7 @@ use clap_verbosity_flag::Verbosity;
 
 use crate::Error;
 
+mod archive;
 mod generate;
 mod prepare;
 mod solutions;
@@ -23,6 +24,8 @@ pub struct Cmd {
 
 #[derive(Debug, Subcommand, Clone)]
 pub enum Commands {
+    /// Archive of solved and generated puzzles
+    Archive(archive::Cmd),
     /// Generate random letter string for puzzle
     Generate(generate::Cmd),
     /// Prepare word list
@@ -36,6 +39,7 @@ pub enum Commands {
 impl Display for Commands {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Commands::Archive(_) => write!(f, "archive"),
             Commands::Generate(_) => write!(f, "generate"),
             Commands::Prepare(_) => write!(f, "prepare"),
             Commands::Solutions(_) => write!(f, "solutions"),
@@ -47,6 +51,7 @@ impl Display for Commands {
 impl Cmd {
     pub fn run(self, settings: HashMap<String, String>) -> Result<(), Error> {
         match self.cmd {
+            Commands::Archive(archive) => archive.run(settings),
             Commands::Generate(generate) => generate.run(settings),
             Commands::Prepare(prepare) => prepare.run(settings),
             Commands::Solutions(solutions) => solutions.run(settings),
@@ -0,0 +1,51 @@
+use std::{collections::HashMap, fmt::Display};
+
+use clap::{Parser, Subcommand};
+use clap_verbosity_flag::Verbosity;
+
+use crate::Error;
+
+mod list;
+mod replay;
+mod show;
+
+#[derive(Parser, Debug, Clone)]
+#[clap(author, version, about, long_about = None)]
+pub struct Cmd {
+    /// logging level
+    #[clap(flatten)]
+    pub logging: Verbosity,
+    /// Commands to run
+    #[command(subcommand)]
+    pub cmd: Commands,
+}
+
+#[derive(Debug, Subcommand, Clone)]
+pub enum Commands {
+    /// List the archived puzzles
+    List(list::Cmd),
+    /// Show an archived puzzle
+    Show(show::Cmd),
+    /// Solve archived puzzles again and compare the results
+    Replay(replay::Cmd),
+}
+
+impl Display for Commands {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Commands::List(_) => write!(f, "list"),
+            Commands::Show(_) => write!(f, "show"),
+            Commands::Replay(_) => write!(f, "replay"),
+        }
+    }
+}
+
+impl Cmd {
+    pub fn run(self, settings: HashMap<String, String>) -> Result<(), Error> {
+        match self.cmd {
+            Commands::List(list) => list.run(settings),
+            Commands::Show(show) => show.run(settings),
+            Commands::Replay(replay) => replay.run(settings),
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use std::collections::HashMap;
+
+use clap::Parser;
+
+use crate::{Archive, Error};
+
+#[derive(Parser, Debug, Clone)]
+pub struct Cmd {
+    /// archive directory
+    #[arg(long)]
+    pub archive_dir: Option<String>,
+}
+
+impl Cmd {
+    pub fn run(self, settings: HashMap<String, String>) -> Result<(), Error> {
+        tracing::debug!("Args: {self:#?}");
+
+        let archive = Archive::new(self.archive_dir.clone(), &settings);
+        let entries = archive.list()?;
+
+        println!("{}", archive.list_string(&entries));
+        Ok(())
+    }
+}
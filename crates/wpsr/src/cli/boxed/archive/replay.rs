@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+
+use clap::Parser;
+use colorful::Colorful;
+
+use crate::{Archive, Error, WordList, cli::Source};
+
+#[derive(Parser, Debug, Clone)]
+pub struct Cmd {
+    /// archived puzzles to replay, or every puzzle when none are given
+    pub ids: Vec<String>,
+    /// archive directory
+    #[arg(long)]
+    pub archive_dir: Option<String>,
+}
+
+impl Cmd {
+    pub fn run(self, settings: HashMap<String, String>) -> Result<(), Error> {
+        tracing::debug!("Args: {self:#?}");
+
+        let archive = Archive::new(self.archive_dir.clone(), &settings);
+        let entries = if self.ids.is_empty() {
+            archive.list()?
+        } else {
+            self.ids
+                .iter()
+                .map(|id| archive.get(id))
+                .collect::<Result<Vec<_>, Error>>()?
+        };
+
+        let mut changed = 0;
+        for entry in entries.iter() {
+            let source = Source {
+                min_frequency: entry.min_frequency,
+                common: entry.common,
+                include_proper_nouns: entry.include_proper_nouns,
+                ..Default::default()
+            };
+            let words = source.select(WordList::load(&entry.word_source)?, &settings)?;
+            let solution = entry.replay(&words)?;
+            if entry.matches(&solution) {
+                println!("  {} {}", "same   ".green(), entry.id());
+            } else {
+                changed += 1;
+                println!("  {} {}", "changed".red().bold(), entry.id());
+                println!(
+                    "      was: {:3} solutions  {}",
+                    entry.solution_count,
+                    entry.best_solution.as_deref().unwrap_or("-")
+                );
+                println!(
+                    "      now: {:3} solutions  {}",
+                    solution.solutions().len(),
+                    solution.best_solution().map_or("-", |s| s.as_str())
+                );
+            }
+        }
+
+        if changed > 0 {
+            return Err(Error::BoxedReplay(changed));
+        }
+        Ok(())
+    }
+}
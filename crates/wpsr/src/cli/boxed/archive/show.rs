@@ -0,0 +1,28 @@
+use std::collections::HashMap;
+
+use clap::Parser;
+
+use crate::{Archive, Error};
+
+#[derive(Parser, Debug, Clone)]
+pub struct Cmd {
+    /// archived puzzle, as listed by `boxed archive list`
+    pub id: String,
+    /// archive directory
+    #[arg(long)]
+    pub archive_dir: Option<String>,
+}
+
+impl Cmd {
+    pub fn run(self, settings: HashMap<String, String>) -> Result<(), Error> {
+        tracing::debug!("Args: {self:#?}");
+
+        let archive = Archive::new(self.archive_dir.clone(), &settings);
+        let entry = archive.get(&self.id)?;
+
+        let text = toml::to_string(&entry).map_err(|e| Error::BoxedArchive(e.to_string()))?;
+
+        println!("{text}");
+        Ok(())
+    }
+}
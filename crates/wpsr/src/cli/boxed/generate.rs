@@ -1,9 +1,10 @@
 use std::collections::HashMap;
 
-use crate::{Error, Shape, Shuffle, Solution};
+use crate::{Archive, ArchiveEntry, Error, Shape, Shuffle, Solution, cli::Source};
 use clap::Parser;
 use indicatif::ProgressBar;
-use rand::seq::SliceRandom;
+use rand::{SeedableRng, seq::SliceRandom};
+use rand_chacha::ChaCha20Rng;
 
 const ALPHABET: &str = "abcdefghijklmnopqrstuvwxyz";
 
@@ -16,13 +17,23 @@ pub struct Cmd {
     // Testing
     #[arg(long, hide = true)]
     pub testing: bool,
+    /// seed for the random number generator
+    #[arg(long)]
+    pub seed: Option<u64>,
+    /// record the puzzle and its solutions in the archive
+    #[arg(short, long)]
+    pub archive: bool,
+    /// archive directory
+    #[arg(long)]
+    pub archive_dir: Option<String>,
 }
 
 impl Cmd {
     #[tracing::instrument(skip(self))]
     pub fn run(self, settings: HashMap<String, String>) -> Result<(), Error> {
+        let seed = self.seed.unwrap_or_else(rand::random);
         let mut letter_pool = ALPHABET.chars().collect::<Vec<char>>();
-        letter_pool.shuffle(&mut rand::rng());
+        letter_pool.shuffle(&mut ChaCha20Rng::seed_from_u64(seed));
 
         let letters = letter_pool
             .iter()
@@ -37,33 +48,19 @@ impl Cmd {
             }
         }
 
-        let mut solution = Solution::new(&letters, settings)?;
-        solution
-            .set_word_source(None, None)
-            .load_words()
-            .find_best_solution()?;
-
-        let mut max_clashes = 10;
-        let mut max_solutions = 100;
+        let archive = Archive::new(self.archive_dir.clone(), &settings);
+        let words = Source::default().load_boxed(&settings)?;
+        let mut solution = Solution::new(&letters, &words)?;
+        solution.set_seed(Some(seed)).find_best_solution()?;
 
+        let max_solutions = 100;
         let bar = ProgressBar::new(max_solutions as u64);
-        while max_solutions > 0 && max_clashes > 0 {
-            tracing::info!(
-                "Generating random solutions max_solutions={max_solutions} and max_clashes={max_clashes}"
-            );
-            match solution.find_random_solution(Shuffle::Once) {
-                Ok(_) => {
-                    max_solutions -= 1;
-                    bar.inc(1);
-                }
-                Err(Error::SolutionAlreadyFound) => {
-                    max_clashes -= 1;
-                }
-                Err(e) => {
-                    tracing::error!("Failed to build word chain: {}", e);
-                    max_solutions -= 1;
-                }
-            };
+        solution.find_random_solutions(max_solutions, &bar);
+
+        if self.archive {
+            let entry =
+                ArchiveEntry::new("generate", &solution, 10, 3, &Shuffle::Once, max_solutions);
+            archive.record(&entry)?;
         }
 
         println!("{}", solution.solutions_title());
@@ -1,13 +1,12 @@
-use std::{collections::VecDeque, io::Write, path::PathBuf, str::FromStr};
+use std::path::PathBuf;
 
 use clap::Parser;
 
-use crate::{Error, WordFilters};
+use crate::{Error, ListFormat, WordList, cli::Writer};
 
 const DEFAULT_SOURCE_DIR: &str = "words";
 const DEFAULT_SOURCE_FILE: &str = "mit_words.txt";
 const DEFAULT_MINIMUM_WORD_LENGTH: &str = "3";
-const LINE_LENGTH: usize = 3010;
 
 #[derive(Parser, Debug, Clone)]
 pub struct Cmd {
@@ -20,6 +19,8 @@ pub struct Cmd {
     /// minimum word length
     #[arg(short, long)]
     pub minimum: Option<usize>,
+    #[command(flatten)]
+    pub writer: Writer,
 }
 
 impl Cmd {
@@ -52,63 +53,23 @@ impl Cmd {
 
         let src = format!("{}/{}", src_directory.clone(), src_file.clone());
 
-        let mut words = Vec::new();
-
-        for line in std::fs::read_to_string(&src)
-            .expect("Failed to read words file")
-            .lines()
-        {
-            if !line.is_empty() {
-                let ws = line.split_whitespace();
-                for w in ws {
-                    words.push(w.to_string());
-                }
-            }
-        }
-
+        let words = WordList::load(&src)?.fold_case();
         tracing::info!("Loaded {} words", words.len());
-        let mut words = words.filter_to_minimum_length(minimum_word_length);
-        words.filter_no_repeated_letters();
-
-        tracing::info!("Filtered words includes {} words", words.len());
-
-        write_words(words, &src);
-
-        Ok(())
+        let list = words.filter(|w| {
+            let lower = w.to_lowercase();
+            lower.len() >= minimum_word_length
+                && lower
+                    .chars()
+                    .zip(lower.chars().skip(1))
+                    .all(|(a, b)| a != b)
+        });
+        tracing::info!("Filtered words includes {} words", list.len());
+
+        let mut dest = PathBuf::from(src);
+        dest.set_extension(match self.writer.format {
+            ListFormat::Text => "slb",
+            ListFormat::Binary => self.writer.format.extension(),
+        });
+        self.writer.write(&list, &dest)
     }
 }
-
-fn write_words(words: Vec<String>, src: &str) {
-    let lines = words_to_lines(words);
-
-    let mut path = PathBuf::from_str(src).unwrap();
-    path.set_extension("slb");
-
-    let mut file = std::fs::File::create(path).unwrap();
-    for line in lines {
-        file.write_all(line.as_bytes()).unwrap();
-        file.write_all(b"\n").unwrap();
-    }
-}
-
-fn words_to_lines(words: Vec<String>) -> Vec<String> {
-    let mut lines = Vec::new();
-    let mut line = String::new();
-    let mut words = VecDeque::from(words);
-
-    while !words.is_empty() {
-        let word = words.pop_front().unwrap();
-        if line.is_empty() {
-            line = word;
-            continue;
-        }
-        if line.len() + word.len() > LINE_LENGTH {
-            lines.push(line.clone());
-            line.clear();
-        }
-        line.push(' ');
-        line.push_str(&word);
-    }
-
-    lines
-}
@@ -1,17 +1,13 @@
 use std::collections::HashMap;
 
-use crate::{Error, Shuffle, Solution};
+use crate::{Archive, ArchiveEntry, Error, Shuffle, Solution, cli::Source};
 use clap::Parser;
 
 #[derive(Parser, Debug, Clone)]
 pub struct Cmd {
     pub letters: String,
-    /// word list source directory
-    #[arg(short, long)]
-    pub dir: Option<String>,
-    /// word list source file
-    #[arg(short, long)]
-    pub file: Option<String>,
+    #[command(flatten)]
+    pub source: Source,
     /// number of random solutions to generate
     #[arg(short, long, default_value_t = 100)]
     pub random_solutions: usize,
@@ -21,6 +17,15 @@ pub struct Cmd {
     /// Shuffle depth
     #[arg(short, long, default_value_t = 3)]
     pub shuffle_depth: i8,
+    /// seed for the random number generator
+    #[arg(long)]
+    pub seed: Option<u64>,
+    /// record the puzzle and its solutions in the archive
+    #[arg(short, long)]
+    pub archive: bool,
+    /// archive directory
+    #[arg(long)]
+    pub archive_dir: Option<String>,
 }
 
 impl Cmd {
@@ -28,36 +33,33 @@ impl Cmd {
     pub fn run(&self, settings: HashMap<String, String>) -> Result<(), Error> {
         tracing::debug!("Args: {self:#?}");
 
-        let mut solution = Solution::new(&self.letters, settings)?;
+        let archive = Archive::new(self.archive_dir.clone(), &settings);
+        let words = self.source.load_boxed(&settings)?;
+        let mut solution = Solution::new(&self.letters, &words)?;
         solution
-            .set_word_source(self.dir.clone(), self.file.clone())
-            .load_words()
             .set_max_chain(self.max_chain)
             .set_shuffle_depth(self.shuffle_depth)
+            .set_seed(self.seed)
             .find_best_solution()?;
 
-        let mut max_clashes = 10;
-        let mut max_solutions = self.random_solutions;
+        let bar = indicatif::ProgressBar::new(self.random_solutions as u64);
+        solution.find_random_solutions(self.random_solutions, &bar);
+        bar.finish();
 
-        let bar = indicatif::ProgressBar::new(max_solutions as u64);
-        while max_solutions > 0 && max_clashes > 0 {
-            tracing::info!(
-                "Generating random solutions max_solutions={max_solutions} and max_clashes={max_clashes}"
+        if self.archive {
+            let mut entry = ArchiveEntry::new(
+                "solutions",
+                &solution,
+                self.max_chain,
+                self.shuffle_depth,
+                &Shuffle::Once,
+                self.random_solutions,
             );
-            match solution.find_random_solution(Shuffle::Once) {
-                Ok(_) => {
-                    max_solutions -= 1;
-                    bar.inc(1);
-                }
-                Err(Error::SolutionAlreadyFound) => {
-                    max_clashes -= 1;
-                }
-                Err(e) => {
-                    tracing::error!("Failed to build word chain: {}", e);
-                }
-            };
+            entry.min_frequency = self.source.min_frequency;
+            entry.common = self.source.common;
+            entry.include_proper_nouns = self.source.include_proper_nouns;
+            archive.record(&entry)?;
         }
-        bar.finish();
 
         println!("{}", solution.solutions_title());
         println!("{}\n", solution.word_source_string());
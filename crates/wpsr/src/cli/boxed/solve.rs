@@ -2,17 +2,13 @@ use std::collections::HashMap;
 
 use clap::Parser;
 
-use crate::{Error, Shuffle, Solution};
+use crate::{Archive, ArchiveEntry, Error, Shuffle, Solution, cli::Source};
 
 #[derive(Parser, Debug, Clone)]
 pub struct Cmd {
     pub letters: String,
-    /// word list source directory
-    #[arg(short, long)]
-    pub dir: Option<String>,
-    /// word list source file
-    #[arg(short, long)]
-    pub file: Option<String>,
+    #[command(flatten)]
+    pub source: Source,
     /// maximum length of the word chain
     #[arg(short, long, default_value_t = 10)]
     pub max_chain: usize,
@@ -27,19 +23,44 @@ pub struct Cmd {
     /// Shuffle depth
     #[arg(short, long, default_value_t = 3)]
     pub layers: i8,
+    /// seed for the random number generator
+    #[arg(long)]
+    pub seed: Option<u64>,
+    /// record the puzzle and its solution in the archive
+    #[arg(short, long)]
+    pub archive: bool,
+    /// archive directory
+    #[arg(long)]
+    pub archive_dir: Option<String>,
 }
 
 impl Cmd {
     pub fn run(self, settings: HashMap<String, String>) -> Result<(), Error> {
         tracing::debug!("Args: {self:#?}");
 
-        let mut solution = Solution::new(&self.letters, settings)?;
+        let archive = Archive::new(self.archive_dir.clone(), &settings);
+        let words = self.source.load_boxed(&settings)?;
+        let mut solution = Solution::new(&self.letters, &words)?;
         solution
-            .set_word_source(self.dir.clone(), self.file.clone())
-            .load_words()
             .set_max_chain(self.max_chain)
             .set_shuffle_depth(self.layers)
-            .find_random_solution(self.shuffle)?;
+            .set_seed(self.seed)
+            .find_random_solution(self.shuffle.clone())?;
+
+        if self.archive {
+            let mut entry = ArchiveEntry::new(
+                "solve",
+                &solution,
+                self.max_chain,
+                self.layers,
+                &self.shuffle,
+                0,
+            );
+            entry.min_frequency = self.source.min_frequency;
+            entry.common = self.source.common;
+            entry.include_proper_nouns = self.source.include_proper_nouns;
+            archive.record(&entry)?;
+        }
 
         println!("{}", solution.solve_title());
         println!("{}\n", solution.word_source_string());
@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+
+use crate::{Codeword, Error, cli::Source};
+use clap::Parser;
+
+#[derive(Parser, Debug, Clone)]
+pub struct Cmd {
+    /// grid of numbers with rows separated by `/`
+    #[arg(
+        long_help = "Grid of numbers 1 to 26, one number for each letter, with `#` for black squares.\nCells are separated by spaces or commas and rows by `/` or new lines, for example `1 2 3 / 4 # 5 / 6 5 7`."
+    )]
+    pub grid: String,
+    #[command(flatten)]
+    pub source: Source,
+    /// maximum number of solutions to print
+    #[arg(short, long, default_value_t = 1)]
+    pub max: usize,
+    /// known letters as comma separated number=letter pairs
+    #[arg(
+        short,
+        long,
+        long_help = "Known letters as comma separated number=letter pairs, for example `3=e,17=t`.\nPinned letters are kept in every solution that is searched."
+    )]
+    pub known: Option<String>,
+}
+
+impl Cmd {
+    #[tracing::instrument(skip(self, settings))]
+    pub fn run(&self, settings: HashMap<String, String>) -> Result<(), Error> {
+        tracing::debug!("Args: {self:#?}");
+
+        let words = self.source.load(&settings)?;
+        let mut solution = Codeword::new(&self.grid, &words)?;
+        solution
+            .set_max_solutions(self.max)
+            .set_known(self.known.clone())?
+            .find_solutions()?;
+
+        println!("{}", solution.solutions_title());
+        println!("{}\n", solution.word_source_string());
+        println!("{}", solution.solutions_string());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use std::{collections::HashMap, fmt::Display};
+
+use clap::{Parser, Subcommand};
+use clap_verbosity_flag::Verbosity;
+
+use crate::Error;
+
+mod letters;
+mod numbers;
+
+#[derive(Parser, Debug, Clone)]
+#[clap(author, version, about, long_about = None)]
+pub struct Cmd {
+    /// logging level
+    #[clap(flatten)]
+    pub logging: Verbosity,
+    /// Commands to run
+    #[command(subcommand)]
+    pub cmd: Commands,
+}
+
+#[derive(Debug, Subcommand, Clone)]
+pub enum Commands {
+    /// Find the longest words in a selection of nine letters
+    Letters(letters::Cmd),
+    /// Reach a target using arithmetic on six numbers
+    Numbers(numbers::Cmd),
+}
+
+impl Display for Commands {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Commands::Letters(_) => write!(f, "letters"),
+            Commands::Numbers(_) => write!(f, "numbers"),
+        }
+    }
+}
+
+impl Cmd {
+    pub fn run(self, settings: HashMap<String, String>) -> Result<(), Error> {
+        match self.cmd {
+            Commands::Letters(letters) => letters.run(settings),
+            Commands::Numbers(numbers) => numbers.run(settings),
+        }
+    }
+}
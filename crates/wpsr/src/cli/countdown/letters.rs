@@ -0,0 +1,32 @@
+use std::collections::HashMap;
+
+use crate::{Error, LettersRound, cli::Source};
+use clap::Parser;
+
+#[derive(Parser, Debug, Clone)]
+pub struct Cmd {
+    pub letters: String,
+    #[command(flatten)]
+    pub source: Source,
+    /// maximum number of solutions to print
+    #[arg(short, long, default_value_t = 10)]
+    pub max: usize,
+}
+
+impl Cmd {
+    #[tracing::instrument(skip(self, settings))]
+    pub fn run(&self, settings: HashMap<String, String>) -> Result<(), Error> {
+        tracing::debug!("Args: {self:#?}");
+
+        let words = self.source.load(&settings)?;
+        let mut solution = LettersRound::new(&self.letters, &words)?;
+        solution.set_max_solutions(self.max).find_solutions()?;
+
+        println!("{}", solution.solutions_title());
+        println!("{}\n", solution.word_source_string());
+        println!("{}", solution.distribution_string());
+        println!("{}", solution.solutions_string());
+
+        Ok(())
+    }
+}
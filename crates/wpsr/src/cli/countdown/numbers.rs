@@ -0,0 +1,32 @@
+use std::collections::HashMap;
+
+use crate::{Error, NumbersRound};
+use clap::Parser;
+
+#[derive(Parser, Debug, Clone)]
+pub struct Cmd {
+    /// the six numbers available
+    #[arg(required = true)]
+    pub numbers: Vec<u32>,
+    /// three-digit target to reach
+    #[arg(short, long)]
+    pub target: u32,
+    /// maximum number of solutions to print
+    #[arg(short, long, default_value_t = 10)]
+    pub max: usize,
+}
+
+impl Cmd {
+    #[tracing::instrument(skip(self, _settings))]
+    pub fn run(&self, _settings: HashMap<String, String>) -> Result<(), Error> {
+        tracing::debug!("Args: {self:#?}");
+
+        let mut solution = NumbersRound::new(&self.numbers, self.target)?;
+        solution.set_limit(self.max).find_solutions()?;
+
+        println!("{}\n", solution.solutions_title());
+        println!("{}", solution.solutions_string());
+
+        Ok(())
+    }
+}
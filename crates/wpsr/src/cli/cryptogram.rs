@@ -0,0 +1,42 @@
+use std::collections::HashMap;
+
+use crate::{Cryptogram, Error, cli::Source};
+use clap::Parser;
+
+#[derive(Parser, Debug, Clone)]
+pub struct Cmd {
+    /// ciphertext to decrypt
+    pub ciphertext: String,
+    #[command(flatten)]
+    pub source: Source,
+    /// maximum number of keys to print
+    #[arg(short, long, default_value_t = 5)]
+    pub max: usize,
+    /// known letters as comma separated cipher=plain pairs
+    #[arg(
+        short,
+        long,
+        long_help = "Known letters as comma separated cipher=plain pairs, for example `X=e,Q=t`.\nPinned letters are kept in every key that is searched."
+    )]
+    pub known: Option<String>,
+}
+
+impl Cmd {
+    #[tracing::instrument(skip(self, settings))]
+    pub fn run(&self, settings: HashMap<String, String>) -> Result<(), Error> {
+        tracing::debug!("Args: {self:#?}");
+
+        let words = self.source.load(&settings)?;
+        let mut solution = Cryptogram::new(&self.ciphertext, &words)?;
+        solution
+            .set_max_solutions(self.max)
+            .set_known(self.known.clone())?
+            .find_solutions()?;
+
+        println!("{}", solution.solutions_title());
+        println!("{}\n", solution.word_source_string());
+        println!("{}", solution.solutions_string());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use std::collections::HashMap;
+
+use crate::{Error, Fill, cli::Source};
+use clap::Parser;
+
+#[derive(Parser, Debug, Clone)]
+pub struct Cmd {
+    /// grid template with rows separated by `/`
+    #[arg(
+        long_help = "Grid template with rows separated by `/` or new lines.\nUse `#` for blocks, `.` or `_` for empty cells and letters for cells that are already filled, for example `c..#/.#../....`."
+    )]
+    pub template: String,
+    #[command(flatten)]
+    pub source: Source,
+    /// seed for the random number generator
+    #[arg(long)]
+    pub seed: Option<u64>,
+}
+
+impl Cmd {
+    #[tracing::instrument(skip(self, settings))]
+    pub fn run(&self, settings: HashMap<String, String>) -> Result<(), Error> {
+        tracing::debug!("Args: {self:#?}");
+
+        let words = self.source.load(&settings)?;
+        let mut solution = Fill::new(&self.template, &words)?;
+        solution.set_seed(self.seed).fill()?;
+
+        println!("{}", solution.solutions_title());
+        println!("{}\n", solution.word_source_string());
+        println!("{}", solution.solutions_string());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use std::collections::HashMap;
+
+use crate::{Error, Hangman, cli::Source};
+use clap::Parser;
+
+#[derive(Parser, Debug, Clone)]
+pub struct Cmd {
+    /// revealed pattern with `_` for each unknown letter, e.g. `_a__e_`
+    pub pattern: String,
+    /// letters guessed that are not in the word
+    pub wrong: Option<String>,
+    #[command(flatten)]
+    pub source: Source,
+    /// maximum number of candidates to print
+    #[arg(short, long, default_value_t = 20)]
+    pub max: usize,
+}
+
+impl Cmd {
+    #[tracing::instrument(skip(self, settings))]
+    pub fn run(&self, settings: HashMap<String, String>) -> Result<(), Error> {
+        tracing::debug!("Args: {self:#?}");
+
+        let words = self.source.load(&settings)?;
+        let mut solution = Hangman::new(&self.pattern, &words)?;
+        solution
+            .set_max_solutions(self.max)
+            .set_wrong(self.wrong.clone())?
+            .find_solutions()?;
+
+        println!("{}", solution.solutions_title());
+        println!("{}\n", solution.word_source_string());
+        println!("{}", solution.recommendations_string());
+        println!("{}", solution.solutions_string());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,39 @@
+use std::collections::HashMap;
+
+use crate::{Error, Jumble, cli::Source};
+use clap::Parser;
+
+#[derive(Parser, Debug, Clone)]
+pub struct Cmd {
+    /// scrambled words with the circled positions, e.g. `nagle:1,3`
+    #[arg(required = true)]
+    pub clues: Vec<String>,
+    #[command(flatten)]
+    pub source: Source,
+    /// shape of the final answer, e.g. `___ _____`
+    #[arg(short, long)]
+    pub answer: Option<String>,
+    /// maximum number of final answers to print for each set of circled letters
+    #[arg(short, long, default_value_t = 10)]
+    pub max: usize,
+}
+
+impl Cmd {
+    #[tracing::instrument(skip(self, settings))]
+    pub fn run(&self, settings: HashMap<String, String>) -> Result<(), Error> {
+        tracing::debug!("Args: {self:#?}");
+
+        let words = self.source.load(&settings)?;
+        let mut solution = Jumble::new(&self.clues, &words)?;
+        solution
+            .set_max_solutions(self.max)
+            .set_answer(self.answer.clone())?
+            .find_solutions()?;
+
+        println!("{}", solution.solutions_title());
+        println!("{}\n", solution.word_source_string());
+        println!("{}", solution.solutions_string());
+
+        Ok(())
+    }
+}
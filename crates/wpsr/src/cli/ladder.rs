@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+
+use crate::{Error, Ladder, cli::Source};
+use clap::Parser;
+
+#[derive(Parser, Debug, Clone)]
+pub struct Cmd {
+    /// word to start the ladder from
+    #[arg(required_unless_present = "diameter")]
+    pub start: Option<String>,
+    /// word to end the ladder on
+    #[arg(required_unless_present = "diameter")]
+    pub end: Option<String>,
+    #[command(flatten)]
+    pub source: Source,
+    /// maximum number of ladders to print
+    #[arg(short, long, default_value_t = 10)]
+    pub max: usize,
+    /// allow steps that add or remove a letter
+    #[arg(short, long)]
+    pub resize: bool,
+    /// report the longest ladders between words of this length
+    #[arg(long, conflicts_with_all = ["start", "end", "resize"])]
+    pub diameter: Option<usize>,
+}
+
+impl Cmd {
+    #[tracing::instrument(skip(self, settings))]
+    pub fn run(&self, settings: HashMap<String, String>) -> Result<(), Error> {
+        tracing::debug!("Args: {self:#?}");
+
+        let words = self.source.load(&settings)?;
+        let mut solution = Ladder::new(&words);
+        solution.set_max_solutions(self.max).set_resize(self.resize);
+
+        if let Some(length) = self.diameter {
+            solution.find_diameter(length)?;
+        } else if let (Some(start), Some(end)) = (&self.start, &self.end) {
+            solution.find_ladders(start, end)?;
+        }
+
+        println!("{}", solution.solutions_title());
+        println!("{}\n", solution.word_source_string());
+        println!("{}", solution.solutions_string());
+
+        Ok(())
+    }
+}
@@ -3,13 +3,16 @@ use std::{collections::HashMap, fs::read_dir};
 use clap::Parser;
 use colorful::Colorful;
 
-use crate::{DEFAULT_SOURCE_DIR, Error};
+use crate::{DEFAULT_SOURCE_DIR, Error, ListFormat, ListStats};
 
 #[derive(Parser, Debug, Clone)]
 pub struct Cmd {
     /// word list source directory
     #[arg(short, long)]
     pub dir: Option<String>,
+    /// report statistics for each word list
+    #[arg(short, long)]
+    pub stats: bool,
 }
 
 impl Cmd {
@@ -25,32 +28,45 @@ impl Cmd {
         };
 
         // List the word list files
-        let mut title = "Word lists:".yellow().bold().underlined().to_string();
+        let title = "Word lists:".yellow().bold().underlined().to_string();
         println!("{title}");
-        for entry in read_dir(&src_directory).unwrap().flatten() {
-            let path = entry.path();
-            if path.is_file() && path.extension().is_some() && path.extension().unwrap() == "txt" {
-                let file_name = path.file_name().unwrap().to_str().unwrap();
-                println!("  {file_name}");
-            }
-        }
+        list_files(&src_directory, ListFormat::Text.extension(), self.stats)?;
 
         // List the boxed puzzle word list files
-        title = "Word lists for boxed puzzles:"
+        let title = "Word lists for boxed puzzles:"
             .yellow()
             .bold()
             .underlined()
             .to_string();
         println!("\n{title}");
+        list_files(&src_directory, "slb", self.stats)?;
 
-        for entry in read_dir(&src_directory).unwrap().flatten() {
-            let path = entry.path();
-            if path.is_file() && path.extension().is_some() && path.extension().unwrap() == "slb" {
-                let file_name = path.file_name().unwrap().to_str().unwrap();
-                println!("  {file_name}");
-            }
-        }
+        // List the binary word list files
+        let title = "Binary word lists:"
+            .yellow()
+            .bold()
+            .underlined()
+            .to_string();
+        println!("\n{title}");
+        list_files(&src_directory, ListFormat::Binary.extension(), self.stats)?;
 
         Ok(())
     }
 }
+
+fn list_files(src_directory: &str, extension: &str, stats: bool) -> Result<(), Error> {
+    for entry in read_dir(src_directory).unwrap().flatten() {
+        let path = entry.path();
+        if path.is_file() && path.extension().is_some_and(|e| e == extension) {
+            let file_name = path.file_name().unwrap().to_str().unwrap();
+            println!("  {file_name}");
+            if stats {
+                println!(
+                    "{}",
+                    ListStats::load(path.to_str().unwrap())?.report_string()
+                );
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use std::{collections::HashMap, fmt::Display, path::PathBuf};
+
+use clap::{Parser, Subcommand};
+use clap_verbosity_flag::Verbosity;
+
+use crate::{DEFAULT_SOURCE_DIR, Error, WordList, cli::Writer};
+
+mod check;
+mod diff;
+mod fix;
+mod intersect;
+mod merge;
+mod subtract;
+
+#[derive(Parser, Debug, Clone)]
+#[clap(author, version, about, long_about = None)]
+pub struct Cmd {
+    /// logging level
+    #[clap(flatten)]
+    pub logging: Verbosity,
+    /// Commands to run
+    #[command(subcommand)]
+    pub cmd: Commands,
+}
+
+#[derive(Debug, Subcommand, Clone)]
+pub enum Commands {
+    /// Report duplicates, case, stray characters, order and proper nouns
+    Check(check::Cmd),
+    /// Report the words added and removed between two lists
+    Diff(diff::Cmd),
+    /// Rewrite a list sorted and without duplicates
+    Fix(fix::Cmd),
+    /// Write the words found in every list
+    Intersect(intersect::Cmd),
+    /// Write the words found in any of the lists
+    Merge(merge::Cmd),
+    /// Write the words of the first list that are in none of the others
+    Subtract(subtract::Cmd),
+}
+
+impl Display for Commands {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Commands::Check(_) => write!(f, "check"),
+            Commands::Diff(_) => write!(f, "diff"),
+            Commands::Fix(_) => write!(f, "fix"),
+            Commands::Intersect(_) => write!(f, "intersect"),
+            Commands::Merge(_) => write!(f, "merge"),
+            Commands::Subtract(_) => write!(f, "subtract"),
+        }
+    }
+}
+
+impl Cmd {
+    pub fn run(self, settings: HashMap<String, String>) -> Result<(), Error> {
+        match self.cmd {
+            Commands::Check(check) => check.run(settings),
+            Commands::Diff(diff) => diff.run(settings),
+            Commands::Fix(fix) => fix.run(settings),
+            Commands::Intersect(intersect) => intersect.run(settings),
+            Commands::Merge(merge) => merge.run(settings),
+            Commands::Subtract(subtract) => subtract.run(settings),
+        }
+    }
+}
+
+/// The word list directory given on the command line or in the settings.
+fn list_dir(settings: &HashMap<String, String>, dir: &Option<String>) -> String {
+    dir.clone().unwrap_or_else(|| {
+        settings
+            .get("source_dir")
+            .map_or(DEFAULT_SOURCE_DIR, |v| v)
+            .to_string()
+    })
+}
+
+/// The path of a named list in the word list directory.
+fn list_path(settings: &HashMap<String, String>, dir: &Option<String>, file: &str) -> String {
+    format!("{}/{file}", list_dir(settings, dir))
+}
+
+/// Load each named list from the word list directory.
+fn load_lists(
+    settings: &HashMap<String, String>,
+    dir: &Option<String>,
+    files: &[String],
+) -> Result<Vec<WordList>, Error> {
+    files
+        .iter()
+        .map(|file| WordList::load(&list_path(settings, dir, file)))
+        .collect()
+}
+
+/// Write the result of a set operation to the word list directory.
+fn write_list(
+    settings: &HashMap<String, String>,
+    dir: &Option<String>,
+    output: &str,
+    writer: &Writer,
+    list: &WordList,
+) -> Result<(), Error> {
+    let dest = PathBuf::from(list_path(settings, dir, output));
+    writer.write(list, &dest)?;
+    println!("Wrote {} words to {}", list.len(), dest.display());
+    Ok(())
+}
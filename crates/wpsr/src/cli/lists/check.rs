@@ -0,0 +1,67 @@
+use std::collections::HashMap;
+
+use clap::Parser;
+use colorful::Colorful;
+
+use super::{list_path, load_lists};
+use crate::{Error, ListCheck};
+
+const EXAMPLES: usize = 10;
+
+#[derive(Parser, Debug, Clone)]
+pub struct Cmd {
+    /// word list to check
+    pub file: String,
+    /// word list directory
+    #[arg(short, long)]
+    pub dir: Option<String>,
+    /// word list that keeps capital letters, to find likely proper nouns
+    #[arg(short, long)]
+    pub reference: Option<String>,
+    /// list every word with a problem rather than the first few
+    #[arg(short, long)]
+    pub all: bool,
+}
+
+impl Cmd {
+    pub fn run(self, settings: HashMap<String, String>) -> Result<(), Error> {
+        tracing::debug!("Args: {self:#?}");
+
+        let reference = match &self.reference {
+            Some(file) => load_lists(&settings, &self.dir, std::slice::from_ref(file))?.pop(),
+            None => None,
+        };
+        let check = ListCheck::load(
+            &list_path(&settings, &self.dir, &self.file),
+            reference.as_ref(),
+        )?;
+
+        let title = format!("Checked {} words in {}", check.entries(), self.file);
+        println!("{}", title.clone().yellow().bold());
+        println!("{}", "‾".repeat(title.chars().count()).yellow().bold());
+        for (label, words) in check.problems() {
+            println!("  {label:<22}{:>8}", words.len());
+            if words.is_empty() {
+                continue;
+            }
+            let shown = if self.all {
+                words.len()
+            } else {
+                EXAMPLES.min(words.len())
+            };
+            let mut line = words[..shown].join(" ");
+            if shown < words.len() {
+                line.push_str(" ...");
+            }
+            println!("      {}", line.dim());
+        }
+        if self.reference.is_none() {
+            println!("  {}", "Give --reference to look for proper nouns".dim());
+        }
+
+        match check.problem_count() {
+            0 => Ok(()),
+            count => Err(Error::WordListProblems(self.file, count)),
+        }
+    }
+}
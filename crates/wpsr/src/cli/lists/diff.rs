@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+
+use clap::Parser;
+use colorful::Colorful;
+
+use super::load_lists;
+use crate::Error;
+
+#[derive(Parser, Debug, Clone)]
+pub struct Cmd {
+    /// word list to compare from
+    pub old: String,
+    /// word list to compare to
+    pub new: String,
+    /// word list directory
+    #[arg(short, long)]
+    pub dir: Option<String>,
+    /// list the words added and removed as well as counting them
+    #[arg(short, long)]
+    pub words: bool,
+}
+
+impl Cmd {
+    pub fn run(self, settings: HashMap<String, String>) -> Result<(), Error> {
+        tracing::debug!("Args: {self:#?}");
+
+        let lists = load_lists(&settings, &self.dir, &[self.old.clone(), self.new.clone()])?;
+        let added = lists[1].subtract(&lists[0]);
+        let removed = lists[0].subtract(&lists[1]);
+
+        let title = format!("Changes from {} to {}", self.old, self.new);
+        println!("{}", title.clone().yellow().bold());
+        println!("{}", "‾".repeat(title.chars().count()).yellow().bold());
+        println!("  {} words added", added.len());
+        println!("  {} words removed", removed.len());
+        println!("  {} words in both", lists[0].len() - removed.len());
+
+        if self.words {
+            println!();
+            for word in added.iter() {
+                println!("  {}", format!("+{word}").green());
+            }
+            for word in removed.iter() {
+                println!("  {}", format!("-{word}").red());
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+
+use clap::Parser;
+
+use super::{load_lists, write_list};
+use crate::{Error, cli::Writer, word_list::proper_nouns};
+
+#[derive(Parser, Debug, Clone)]
+pub struct Cmd {
+    /// word list to fix
+    pub file: String,
+    /// word list directory
+    #[arg(short, long)]
+    pub dir: Option<String>,
+    /// word list output file name, replacing the list when not given
+    #[arg(short, long)]
+    pub output: Option<String>,
+    /// word list that keeps capital letters, to remove likely proper nouns
+    #[arg(short, long)]
+    pub reference: Option<String>,
+    #[command(flatten)]
+    pub writer: Writer,
+}
+
+impl Cmd {
+    pub fn run(self, settings: HashMap<String, String>) -> Result<(), Error> {
+        tracing::debug!("Args: {self:#?}");
+
+        let mut files = vec![self.file.clone()];
+        files.extend(self.reference.clone());
+        let lists = load_lists(&settings, &self.dir, &files)?;
+
+        let mut list = lists[0].sorted();
+        if let Some(reference) = lists.get(1) {
+            let proper = proper_nouns(reference);
+            list = list.filter(|w| !proper.contains(&w.to_lowercase()));
+            println!(
+                "Removed {} likely proper nouns",
+                lists[0].len() - list.len()
+            );
+        }
+
+        let output = self.output.as_deref().unwrap_or(&self.file);
+        write_list(&settings, &self.dir, output, &self.writer, &list)
+    }
+}
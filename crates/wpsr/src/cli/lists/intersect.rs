@@ -0,0 +1,35 @@
+use std::collections::HashMap;
+
+use clap::Parser;
+
+use super::{load_lists, write_list};
+use crate::{Error, cli::Writer};
+
+#[derive(Parser, Debug, Clone)]
+pub struct Cmd {
+    /// word lists to intersect
+    #[arg(required = true, num_args = 2..)]
+    pub files: Vec<String>,
+    /// word list directory
+    #[arg(short, long)]
+    pub dir: Option<String>,
+    /// word list output file name
+    #[arg(short, long)]
+    pub output: String,
+    #[command(flatten)]
+    pub writer: Writer,
+}
+
+impl Cmd {
+    pub fn run(self, settings: HashMap<String, String>) -> Result<(), Error> {
+        tracing::debug!("Args: {self:#?}");
+
+        let lists = load_lists(&settings, &self.dir, &self.files)?;
+        let list = lists
+            .iter()
+            .skip(1)
+            .fold(lists[0].clone(), |all, list| all.intersect(list));
+
+        write_list(&settings, &self.dir, &self.output, &self.writer, &list)
+    }
+}
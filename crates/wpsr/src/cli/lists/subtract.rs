@@ -0,0 +1,42 @@
+use std::collections::HashMap;
+
+use clap::Parser;
+
+use super::{load_lists, write_list};
+use crate::{Error, cli::Writer};
+
+#[derive(Parser, Debug, Clone)]
+pub struct Cmd {
+    /// word list to remove words from
+    pub base: String,
+    /// word lists of the words to remove
+    #[arg(required = true)]
+    pub remove: Vec<String>,
+    /// word list directory
+    #[arg(short, long)]
+    pub dir: Option<String>,
+    /// word list output file name
+    #[arg(short, long)]
+    pub output: String,
+    #[command(flatten)]
+    pub writer: Writer,
+}
+
+impl Cmd {
+    pub fn run(self, settings: HashMap<String, String>) -> Result<(), Error> {
+        tracing::debug!("Args: {self:#?}");
+
+        let base = load_lists(&settings, &self.dir, std::slice::from_ref(&self.base))?;
+        let remove = load_lists(&settings, &self.dir, &self.remove)?;
+        let list = remove
+            .iter()
+            .fold(base[0].clone(), |rest, list| rest.subtract(list));
+        println!(
+            "Removed {} of {} words",
+            base[0].len() - list.len(),
+            base[0].len()
+        );
+
+        write_list(&settings, &self.dir, &self.output, &self.writer, &list)
+    }
+}
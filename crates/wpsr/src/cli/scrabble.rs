@@ -0,0 +1,42 @@
+use std::collections::HashMap;
+
+use crate::{Error, Scrabble, cli::Source};
+use clap::Parser;
+
+#[derive(Parser, Debug, Clone)]
+pub struct Cmd {
+    /// tiles on the rack, using `?` for a blank
+    pub rack: String,
+    #[command(flatten)]
+    pub source: Source,
+    /// maximum number of solutions to print
+    #[arg(short, long, default_value_t = 50)]
+    pub max: usize,
+    /// board line to play on
+    #[arg(
+        short,
+        long,
+        long_help = "Board line to play on\n\n. - Empty square\n2 - Double letter square\n3 - Triple letter square\nD - Double word square\nT - Triple word square\na-z - Tile already on the board"
+    )]
+    pub board: Option<String>,
+}
+
+impl Cmd {
+    #[tracing::instrument(skip(self, settings))]
+    pub fn run(&self, settings: HashMap<String, String>) -> Result<(), Error> {
+        tracing::debug!("Args: {self:#?}");
+
+        let words = self.source.load(&settings)?;
+        let mut solution = Scrabble::new(&self.rack, &words)?;
+        solution
+            .set_max_solutions(self.max)
+            .set_board(self.board.clone())?
+            .find_solutions()?;
+
+        println!("{}", solution.solutions_title());
+        println!("{}\n", solution.word_source_string());
+        println!("{}", solution.solutions_string());
+
+        Ok(())
+    }
+}
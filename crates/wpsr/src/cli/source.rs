@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+
+use clap::Args;
+
+use crate::{Error, WordList};
+
+/// Options choosing the word list, shared by every command that reads one.
+#[derive(Args, Debug, Clone, Default)]
+pub struct Source {
+    /// word list source directory
+    #[arg(short, long)]
+    pub dir: Option<String>,
+    /// word list source file
+    #[arg(short, long)]
+    pub file: Option<String>,
+    /// only use words the word list counts at least this often
+    #[arg(long)]
+    pub min_frequency: Option<u32>,
+    /// try the most common words first
+    #[arg(long)]
+    pub common: bool,
+    /// use proper nouns and acronyms as well as common words
+    #[arg(long)]
+    pub include_proper_nouns: bool,
+}
+
+impl Source {
+    pub fn load(&self, settings: &HashMap<String, String>) -> Result<WordList, Error> {
+        self.select(
+            WordList::from_settings(settings, self.dir.clone(), self.file.clone())?,
+            settings,
+        )
+    }
+
+    pub fn load_boxed(&self, settings: &HashMap<String, String>) -> Result<WordList, Error> {
+        self.select(
+            WordList::boxed_from_settings(settings, self.dir.clone(), self.file.clone())?,
+            settings,
+        )
+    }
+
+    /// Leave out proper nouns and acronyms unless asked for them, drop the
+    /// words counted less than the minimum, apply the allowlist and
+    /// blocklist overlays named by the settings and then put the most
+    /// common words first. The minimum comes before the overlays so that
+    /// allowed words are kept whatever their count, and the choice of proper
+    /// nouns applies to allowed words too.
+    pub(crate) fn select(
+        &self,
+        words: WordList,
+        settings: &HashMap<String, String>,
+    ) -> Result<WordList, Error> {
+        let mut words = self.cased(words);
+        if let Some(minimum) = self.min_frequency {
+            words = words.with_min_frequency(minimum)?;
+        }
+        let mut words = self.cased(words.with_overlays(settings)?);
+        if self.common {
+            words = words.most_common_first()?;
+        }
+        Ok(words)
+    }
+
+    fn cased(&self, words: WordList) -> WordList {
+        if self.include_proper_nouns {
+            words.to_lowercase()
+        } else {
+            words.common_words()
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use std::collections::HashMap;
+
+use crate::{Error, TextTwist, cli::Source};
+use clap::Parser;
+
+#[derive(Parser, Debug, Clone)]
+pub struct Cmd {
+    /// the six or seven letters of the round
+    pub letters: String,
+    #[command(flatten)]
+    pub source: Source,
+}
+
+impl Cmd {
+    #[tracing::instrument(skip(self, settings))]
+    pub fn run(&self, settings: HashMap<String, String>) -> Result<(), Error> {
+        tracing::debug!("Args: {self:#?}");
+
+        let words = self.source.load(&settings)?;
+        let mut solution = TextTwist::new(&self.letters, &words)?;
+        solution.find_solutions()?;
+
+        println!("{}", solution.solutions_title());
+        println!("{}\n", solution.word_source_string());
+        println!("{}", solution.board_string());
+        println!("{}", solution.solutions_string());
+
+        Ok(())
+    }
+}
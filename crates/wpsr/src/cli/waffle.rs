@@ -0,0 +1,34 @@
+use std::collections::HashMap;
+
+use crate::{Error, Waffle, cli::Source};
+use clap::Parser;
+
+#[derive(Parser, Debug, Clone)]
+pub struct Cmd {
+    /// letters of the grid row by row, e.g. `sratt/h.r.r/ealge/l.u.s/leers`
+    pub grid: String,
+    /// colours of the tiles row by row, using g, y and b for grey
+    pub colours: String,
+    #[command(flatten)]
+    pub source: Source,
+    /// maximum number of solutions to print
+    #[arg(short, long, default_value_t = 5)]
+    pub max: usize,
+}
+
+impl Cmd {
+    #[tracing::instrument(skip(self, settings))]
+    pub fn run(&self, settings: HashMap<String, String>) -> Result<(), Error> {
+        tracing::debug!("Args: {self:#?}");
+
+        let words = self.source.load(&settings)?;
+        let mut solution = Waffle::new(&self.grid, &self.colours, &words)?;
+        solution.set_max_solutions(self.max).find_solutions()?;
+
+        println!("{}", solution.solutions_title());
+        println!("{}\n", solution.word_source_string());
+        println!("{}", solution.solutions_string());
+
+        Ok(())
+    }
+}
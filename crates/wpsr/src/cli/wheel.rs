@@ -0,0 +1,33 @@
+use std::collections::HashMap;
+
+use crate::{Error, WordWheel, cli::Source};
+use clap::Parser;
+
+#[derive(Parser, Debug, Clone)]
+pub struct Cmd {
+    /// the nine letters of the wheel
+    pub letters: String,
+    #[command(flatten)]
+    pub source: Source,
+    /// centre letter that every word must use (defaults to the first letter)
+    #[arg(short, long)]
+    pub centre: Option<char>,
+}
+
+impl Cmd {
+    #[tracing::instrument(skip(self, settings))]
+    pub fn run(&self, settings: HashMap<String, String>) -> Result<(), Error> {
+        tracing::debug!("Args: {self:#?}");
+
+        let words = self.source.load(&settings)?;
+        let mut solution = WordWheel::new(&self.letters, &words)?;
+        solution.set_centre(self.centre)?.find_solutions()?;
+
+        println!("{}", solution.solutions_title());
+        println!("{}\n", solution.word_source_string());
+        println!("{}", solution.board_string());
+        println!("{}", solution.solutions_string());
+
+        Ok(())
+    }
+}
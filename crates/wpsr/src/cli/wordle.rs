@@ -0,0 +1,43 @@
+use std::collections::HashMap;
+
+use crate::{Error, Wordle, cli::Source};
+use clap::Parser;
+
+#[derive(Parser, Debug, Clone)]
+pub struct Cmd {
+    /// guesses made so far, shared by every board
+    pub guesses: Vec<String>,
+    /// marks shown on a board for each guess, e.g. `bygbb,ggbgg` (repeat for each board)
+    #[arg(
+        short,
+        long = "board",
+        long_help = "Marks shown on a board for each guess, separated by commas, e.g. `bygbb,ggbgg`.\nUse g for green, y for yellow and b for grey. Repeat for each board; a board can\nstop once it has been solved."
+    )]
+    pub boards: Vec<String>,
+    /// number of boards being played, e.g. 4 for Quordle or 8 for Octordle
+    #[arg(short, long, default_value_t = 1)]
+    pub number: usize,
+    #[command(flatten)]
+    pub source: Source,
+    /// maximum number of candidates and suggestions to print
+    #[arg(short, long, default_value_t = 10)]
+    pub max: usize,
+}
+
+impl Cmd {
+    #[tracing::instrument(skip(self, settings))]
+    pub fn run(&self, settings: HashMap<String, String>) -> Result<(), Error> {
+        tracing::debug!("Args: {self:#?}");
+
+        let words = self.source.load(&settings)?;
+        let mut solution = Wordle::new(&self.guesses, &self.boards, self.number, &words)?;
+        solution.set_max_solutions(self.max).find_solutions()?;
+
+        println!("{}", solution.solutions_title());
+        println!("{}\n", solution.word_source_string());
+        println!("{}", solution.boards_string());
+        println!("{}", solution.suggestions_string());
+
+        Ok(())
+    }
+}
@@ -1,17 +1,13 @@
 use std::collections::HashMap;
 
-use crate::{Error, Words};
+use crate::{Error, Words, cli::Source};
 use clap::Parser;
 
 #[derive(Parser, Debug, Clone)]
 pub struct Cmd {
     pub letters: String,
-    /// word list source directory
-    #[arg(short, long)]
-    pub dir: Option<String>,
-    /// word list source file
-    #[arg(short, long)]
-    pub file: Option<String>,
+    #[command(flatten)]
+    pub source: Source,
     /// maximum number of solutions to print
     #[arg(short, long, default_value_t = 100)]
     pub max: usize,
@@ -28,10 +24,9 @@ impl Cmd {
     pub fn run(&self, settings: HashMap<String, String>) -> Result<(), Error> {
         tracing::debug!("Args: {self:#?}");
 
-        let mut solution = Words::new(&self.letters, settings)?;
+        let words = self.source.load(&settings)?;
+        let mut solution = Words::new(&self.letters, &words)?;
         solution
-            .set_word_source(self.dir.clone(), self.file.clone())
-            .load_words()
             .set_max_solutions(self.max)
             .set_required(self.required.clone())
             .set_pangram(self.pangram)
@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+
+use clap::Parser;
+
+use crate::{Directions, Error, PuzzleGenerator, cli::Source};
+
+#[derive(Parser, Debug, Clone)]
+pub struct Cmd {
+    /// words to hide in the grid
+    pub words: Vec<String>,
+    /// theme file of words to hide in the grid
+    #[arg(short, long)]
+    pub theme: Option<String>,
+    #[command(flatten)]
+    pub source: Source,
+    /// number of words to pick from the word list when none are given
+    #[arg(short = 'n', long, default_value_t = 10)]
+    pub count: usize,
+    /// number of rows in the grid
+    #[arg(short, long, default_value_t = 12)]
+    pub rows: usize,
+    /// number of columns in the grid
+    #[arg(short, long, default_value_t = 12)]
+    pub columns: usize,
+    /// Directions words may run in
+    #[arg(
+        short = 'D',
+        long,
+        default_value_t = Directions::All,
+        long_help = "Directions words may run in\n\nAll - All eight directions, including backwards\nForward - Right, down and the two diagonals that read left to right"
+    )]
+    pub directions: Directions,
+    /// letters used to fill the empty cells
+    #[arg(short = 'l', long)]
+    pub fill: Option<String>,
+    /// seed for the random number generator
+    #[arg(long)]
+    pub seed: Option<u64>,
+}
+
+impl Cmd {
+    #[tracing::instrument(skip(self, settings))]
+    pub fn run(self, settings: HashMap<String, String>) -> Result<(), Error> {
+        tracing::debug!("Args: {self:#?}");
+
+        let words = if self.words.is_empty() && self.theme.is_none() {
+            Some(self.source.load(&settings)?)
+        } else {
+            None
+        };
+        let mut generator = PuzzleGenerator::new(self.rows, self.columns)?;
+        if let Some(words) = &words {
+            generator.set_word_list(words);
+        }
+        generator
+            .set_targets(self.words.clone())
+            .load_theme(self.theme.clone())?
+            .set_count(self.count)
+            .set_directions(self.directions)
+            .set_fill(self.fill.clone())?
+            .set_seed(self.seed)
+            .generate()?;
+
+        println!("{}", generator.puzzle_title());
+        println!("{}", generator.word_source_string());
+        println!("{}", generator.puzzle_string());
+        println!("{}", generator.answer_key_string());
+
+        Ok(())
+    }
+}
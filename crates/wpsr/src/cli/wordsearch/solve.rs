@@ -0,0 +1,33 @@
+use std::collections::HashMap;
+
+use clap::Parser;
+
+use crate::{Error, WordSearch};
+
+#[derive(Parser, Debug, Clone)]
+pub struct Cmd {
+    /// grid rows separated by `/`
+    pub grid: String,
+    /// words to find in the grid
+    pub words: Vec<String>,
+    /// theme file of words to find in the grid
+    #[arg(short, long)]
+    pub theme: Option<String>,
+}
+
+impl Cmd {
+    pub fn run(self, _settings: HashMap<String, String>) -> Result<(), Error> {
+        tracing::debug!("Args: {self:#?}");
+
+        let mut solution = WordSearch::new(&self.grid)?;
+        solution
+            .set_targets(self.words.clone())
+            .load_theme(self.theme.clone())?
+            .find_solutions()?;
+
+        println!("{}", solution.solutions_title());
+        println!("{}", solution.solutions_string());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+use std::path::Path;
+
+use clap::Args;
+
+use crate::{Error, ListFormat, WordList};
+
+/// Options choosing how a word list is written, shared by every command that
+/// writes one.
+#[derive(Args, Debug, Clone)]
+pub struct Writer {
+    /// format of the output file, text or binary
+    #[arg(long, default_value_t = ListFormat::Text)]
+    pub format: ListFormat,
+    /// store letter masks in a binary output file
+    #[arg(long)]
+    pub masks: bool,
+    /// store sorted letter signatures in a binary output file
+    #[arg(long)]
+    pub signatures: bool,
+}
+
+impl Writer {
+    pub fn write(&self, list: &WordList, path: &Path) -> Result<(), Error> {
+        match self.format {
+            ListFormat::Text => list.write_text(path),
+            ListFormat::Binary => list.write_binary(path, self.masks, self.signatures),
+        }
+    }
+}
@@ -0,0 +1,429 @@
+use colorful::Colorful;
+
+use crate::{
+    Error, WordList,
+    cryptogram::{PatternIndex, pattern_of},
+};
+
+const CODES: usize = 26;
+const ALL_LETTERS: u32 = (1 << CODES) - 1;
+const SEARCH_LIMIT: usize = 100_000;
+
+/// A grid of numbered cells, each number standing for a letter, with black
+/// squares between the entries.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CodewordGrid {
+    rows: usize,
+    columns: usize,
+    cells: Vec<Option<u8>>,
+}
+
+impl CodewordGrid {
+    /// Parse a grid given one row per line or with rows separated by `/`, with
+    /// the numbers 1 to 26 separated by whitespace or commas and `0`, `#` or
+    /// `.` for black squares.
+    pub fn parse(text: &str) -> Result<Self, Error> {
+        let mut rows = Vec::new();
+        for line in text.split(['\n', '/']).filter(|l| !l.trim().is_empty()) {
+            let mut row = Vec::new();
+            for cell in line
+                .split(|c: char| c.is_whitespace() || c == ',')
+                .filter(|c| !c.is_empty())
+            {
+                match cell {
+                    "#" | "." | "0" => row.push(None),
+                    n => match n.parse::<u8>() {
+                        Ok(code) if (1..=CODES as u8).contains(&code) => row.push(Some(code)),
+                        _ => return Err(Error::CodewordGrid(cell.to_string())),
+                    },
+                }
+            }
+            rows.push(row);
+        }
+
+        let columns = rows.first().map_or(0, |r| r.len());
+        if columns == 0 || rows.iter().any(|r| r.len() != columns) {
+            return Err(Error::CodewordGrid(format!("{} rows", rows.len())));
+        }
+
+        Ok(Self {
+            rows: rows.len(),
+            columns,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// The codes of every run of two or more cells across and down.
+    pub fn entries(&self) -> Vec<Vec<u8>> {
+        let mut entries = Vec::new();
+        let mut push_runs = |line: Vec<Option<u8>>| {
+            for run in line.split(|c| c.is_none()) {
+                if run.len() > 1 {
+                    entries.push(run.iter().flatten().copied().collect());
+                }
+            }
+        };
+        for r in 0..self.rows {
+            push_runs(
+                (0..self.columns)
+                    .map(|c| self.cells[r * self.columns + c])
+                    .collect(),
+            );
+        }
+        for c in 0..self.columns {
+            push_runs(
+                (0..self.rows)
+                    .map(|r| self.cells[r * self.columns + c])
+                    .collect(),
+            );
+        }
+        entries
+    }
+
+    /// The grid with each code replaced by its letter where known.
+    pub fn grid_string(&self, key: &[Option<char>]) -> String {
+        let mut s = String::new();
+        for row in self.cells.chunks(self.columns) {
+            let row = row
+                .iter()
+                .map(|cell| match cell {
+                    None => " #".to_string(),
+                    Some(code) => match key[*code as usize - 1] {
+                        Some(letter) => format!(" {}", letter.to_ascii_uppercase()),
+                        None => format!("{code:2}"),
+                    },
+                })
+                .collect::<Vec<String>>()
+                .join(" ");
+            s.push_str(&format!("   {row}\n"));
+        }
+        s
+    }
+}
+
+/// Letters still possible for each code, as bit sets, and the words still
+/// possible for each entry.
+#[derive(Debug, Clone)]
+struct State {
+    domains: [u32; CODES],
+    candidates: Vec<Vec<usize>>,
+}
+
+#[derive(Debug)]
+pub struct Codeword<'a> {
+    grid: CodewordGrid,
+    known: Vec<Option<char>>,
+    words: &'a WordList,
+    max: usize,
+    solutions: Vec<Vec<Option<char>>>,
+}
+
+impl<'a> Codeword<'a> {
+    pub fn new(grid: &str, words: &'a WordList) -> Result<Self, Error> {
+        Ok(Self {
+            words,
+            grid: CodewordGrid::parse(grid)?,
+            known: vec![None; CODES],
+            max: 1,
+            solutions: Vec::new(),
+        })
+    }
+
+    pub fn set_max_solutions(&mut self, value: usize) -> &mut Self {
+        self.max = value;
+        self
+    }
+
+    /// Pin known letters, given as comma separated `code=letter` pairs such
+    /// as `3=e,17=t`.
+    pub fn set_known(&mut self, value: Option<String>) -> Result<&mut Self, Error> {
+        let mut known = vec![None; CODES];
+        for pair in value.iter().flat_map(|v| v.split(',')) {
+            let Some((code, letter)) = pair.split_once('=') else {
+                return Err(Error::CodewordKey(pair.to_string()));
+            };
+            let code = code.trim().parse::<usize>().unwrap_or(0);
+            let mut letters = letter.trim().chars();
+            match (letters.next(), letters.next()) {
+                (Some(letter), None)
+                    if (1..=CODES).contains(&code)
+                        && letter.is_ascii_alphabetic()
+                        && !known.contains(&Some(letter.to_ascii_lowercase())) =>
+                {
+                    known[code - 1] = Some(letter.to_ascii_lowercase());
+                }
+                _ => return Err(Error::CodewordKey(pair.to_string())),
+            }
+        }
+        self.known = known;
+        Ok(self)
+    }
+
+    pub fn solutions(&self) -> &[Vec<Option<char>>] {
+        &self.solutions
+    }
+
+    /// Match each entry against the words with the same letter pattern, then
+    /// narrow the letters each code can take by propagating the crossings,
+    /// guessing an entry only when propagation stalls.
+    #[tracing::instrument(skip(self))]
+    pub fn find_solutions(&mut self) -> Result<&mut Self, Error> {
+        let index = PatternIndex::new(self.words.iter());
+        let entries = self.grid.entries();
+
+        let entry_words = entries
+            .iter()
+            .map(|entry| {
+                index
+                    .matches(&pattern_of(entry))
+                    .iter()
+                    .map(|w| w.bytes().map(|b| b - b'a').collect::<Vec<u8>>())
+                    .collect::<Vec<Vec<u8>>>()
+            })
+            .collect::<Vec<_>>();
+        tracing::debug!(
+            "Candidates per entry: {:?}",
+            entry_words.iter().map(|w| w.len()).collect::<Vec<_>>()
+        );
+
+        let mut domains = [ALL_LETTERS; CODES];
+        for (code, letter) in self.known.iter().enumerate() {
+            if let Some(letter) = letter {
+                domains[code] = 1 << (*letter as u8 - b'a');
+            }
+        }
+        let state = State {
+            domains,
+            candidates: entry_words.iter().map(|w| (0..w.len()).collect()).collect(),
+        };
+
+        let mut solutions = Vec::new();
+        let mut budget = SEARCH_LIMIT;
+        search(
+            state,
+            &entries,
+            &entry_words,
+            &mut solutions,
+            self.max,
+            &mut budget,
+        );
+        if budget == 0 {
+            tracing::warn!("Search stopped after {SEARCH_LIMIT} guesses");
+        }
+        if solutions.is_empty() {
+            return Err(Error::CodewordUnsolved);
+        }
+
+        self.solutions = solutions;
+        Ok(self)
+    }
+
+    pub fn word_source_string(&self) -> String {
+        self.words.source_string()
+    }
+
+    pub fn solutions_title(&self) -> String {
+        let intro = "Codeword";
+        let mut ul = String::new();
+        for _ in 0..intro.len() {
+            ul.push('‾');
+        }
+
+        format!(
+            "{}\n{}\n\n{}",
+            intro.yellow().bold(),
+            ul.bold().yellow(),
+            self.grid.grid_string(&self.known).blue().bold()
+        )
+    }
+
+    pub fn solutions_string(&self) -> String {
+        let mut s = String::new();
+
+        for (index, key) in self.solutions.iter().enumerate() {
+            s.push_str("  ");
+            s.push_str(
+                &format!("Solution {}", index + 1)
+                    .underlined()
+                    .yellow()
+                    .to_string(),
+            );
+            s.push_str("\n\n");
+            s.push_str(&self.grid.grid_string(key));
+            s.push('\n');
+            for half in key.chunks(CODES / 2).enumerate() {
+                let (offset, letters) = half;
+                let codes = (1..=letters.len())
+                    .map(|c| format!("{:2}", c + offset * CODES / 2))
+                    .collect::<Vec<String>>()
+                    .join(" ");
+                let letters = letters
+                    .iter()
+                    .map(|l| format!(" {}", l.unwrap_or('.').to_ascii_uppercase()))
+                    .collect::<Vec<String>>()
+                    .join(" ");
+                s.push_str(&format!("   {codes}\n   {letters}\n"));
+            }
+            s.push('\n');
+        }
+        s
+    }
+}
+
+fn search(
+    mut state: State,
+    entries: &[Vec<u8>],
+    entry_words: &[Vec<Vec<u8>>],
+    solutions: &mut Vec<Vec<Option<char>>>,
+    max: usize,
+    budget: &mut usize,
+) {
+    if solutions.len() >= max || *budget == 0 {
+        return;
+    }
+    *budget -= 1;
+    if !propagate(&mut state, entries, entry_words) {
+        return;
+    }
+
+    let open = (0..entries.len())
+        .filter(|e| state.candidates[*e].len() > 1)
+        .min_by_key(|e| state.candidates[*e].len());
+    let Some(entry) = open else {
+        let key = state
+            .domains
+            .iter()
+            .map(|d| (d.count_ones() == 1).then(|| (b'a' + d.trailing_zeros() as u8) as char))
+            .collect::<Vec<Option<char>>>();
+        if !solutions.contains(&key) {
+            solutions.push(key);
+        }
+        return;
+    };
+
+    for candidate in state.candidates[entry].clone() {
+        let mut next = state.clone();
+        next.candidates[entry] = vec![candidate];
+        search(next, entries, entry_words, solutions, max, budget);
+        if solutions.len() >= max || *budget == 0 {
+            return;
+        }
+    }
+}
+
+/// Remove the words that no longer fit, narrow each code to the letters its
+/// entries still allow, and take each settled letter away from every other
+/// code. Returns false when an entry or a code runs out of options.
+fn propagate(state: &mut State, entries: &[Vec<u8>], entry_words: &[Vec<Vec<u8>>]) -> bool {
+    loop {
+        let before = state.domains;
+        let mut allowed = [ALL_LETTERS; CODES];
+
+        for (e, entry) in entries.iter().enumerate() {
+            let domains = state.domains;
+            state.candidates[e].retain(|w| {
+                entry
+                    .iter()
+                    .zip(entry_words[e][*w].iter())
+                    .all(|(code, letter)| domains[*code as usize - 1] & (1 << letter) != 0)
+            });
+            if state.candidates[e].is_empty() {
+                return false;
+            }
+
+            let mut seen = vec![0u32; entry.len()];
+            for w in state.candidates[e].iter() {
+                for (i, letter) in entry_words[e][*w].iter().enumerate() {
+                    seen[i] |= 1 << letter;
+                }
+            }
+            for (code, letters) in entry.iter().zip(seen.iter()) {
+                allowed[*code as usize - 1] &= letters;
+            }
+        }
+
+        for (domain, letters) in state.domains.iter_mut().zip(allowed.iter()) {
+            *domain &= letters;
+        }
+        for code in 0..CODES {
+            if state.domains[code].count_ones() == 1 {
+                let letter = state.domains[code];
+                for (other, domain) in state.domains.iter_mut().enumerate() {
+                    if other != code {
+                        *domain &= !letter;
+                    }
+                }
+            }
+        }
+        if state.domains.contains(&0) {
+            return false;
+        }
+        if state.domains == before {
+            return true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // C A T
+    // O # E
+    // W E D
+    const GRID: &str = "1 2 3/4 # 5\n6 5 7";
+
+    #[test]
+    fn test_entries_across_and_down() {
+        let grid = CodewordGrid::parse(GRID).unwrap();
+        assert_eq!(
+            grid.entries(),
+            vec![vec![1, 2, 3], vec![6, 5, 7], vec![1, 4, 6], vec![3, 5, 7]]
+        );
+        assert_eq!(
+            CodewordGrid::parse("1 2\n3 27"),
+            Err(Error::CodewordGrid("27".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_solve_with_crossings() {
+        let words = WordList::from_words(["cat", "wed", "cow", "ted", "bat", "bed", "bow"]);
+        let mut codeword = Codeword::new(GRID, &words).unwrap();
+        codeword.set_max_solutions(5).find_solutions().unwrap();
+
+        // cat or bat crossing cow or bow fits either way round the corner.
+        assert_eq!(codeword.solutions().len(), 4);
+
+        codeword
+            .set_known(Some("1=c,2=a".to_string()))
+            .unwrap()
+            .find_solutions()
+            .unwrap();
+        assert_eq!(codeword.solutions().len(), 1);
+        let key = &codeword.solutions()[0];
+        let word = [1, 2, 3, 4, 6, 5, 7]
+            .iter()
+            .map(|c| key[c - 1].unwrap())
+            .collect::<String>();
+        assert_eq!(word, "catowed");
+    }
+
+    #[test]
+    fn test_invalid_known_letters() {
+        let words = WordList::default();
+        let mut codeword = Codeword::new(GRID, &words).unwrap();
+        assert_eq!(
+            codeword.set_known(Some("1=c,2=c".to_string())).err(),
+            Some(Error::CodewordKey("2=c".to_string()))
+        );
+        assert_eq!(
+            codeword.set_known(Some("27=c".to_string())).err(),
+            Some(Error::CodewordKey("27=c".to_string()))
+        );
+        assert_eq!(
+            codeword.find_solutions().err(),
+            Some(Error::CodewordUnsolved)
+        );
+    }
+}
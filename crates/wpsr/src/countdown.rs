@@ -0,0 +1,5 @@
+mod letters;
+mod numbers;
+
+pub use letters::LettersRound;
+pub use numbers::{NumbersRound, Operation, Step};
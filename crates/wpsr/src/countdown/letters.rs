@@ -0,0 +1,179 @@
+use colorful::Colorful;
+
+use crate::{Distribution, Error, WordList};
+
+const LETTERS_ROUND_LENGTH: usize = 9;
+
+#[derive(Debug)]
+pub struct LettersRound<'a> {
+    letters: Vec<char>,
+    words: &'a WordList,
+    solutions: Vec<String>,
+    max: usize,
+    distribution: Distribution,
+}
+
+impl<'a> LettersRound<'a> {
+    pub fn new(letters: &str, words: &'a WordList) -> Result<Self, Error> {
+        // Anything but a letter is refused, so a `?` is never taken as a blank.
+        if letters.chars().count() != LETTERS_ROUND_LENGTH
+            || !letters.chars().all(|l| l.is_ascii_alphabetic())
+        {
+            return Err(Error::CountdownLetters(letters.to_string()));
+        }
+
+        let letters = letters
+            .chars()
+            .map(|l| l.to_ascii_lowercase())
+            .collect::<Vec<char>>();
+
+        Ok(Self {
+            words,
+            letters,
+            max: 10,
+            solutions: Vec::new(),
+            distribution: Distribution::new(),
+        })
+    }
+
+    pub fn set_max_solutions(&mut self, value: usize) -> &mut Self {
+        self.max = value;
+        self
+    }
+
+    /// Find the longest words that can be made from the letters, using each
+    /// letter no more often than it appears in the selection.
+    #[tracing::instrument(skip(self))]
+    pub fn find_solutions(&mut self) -> Result<&mut Self, Error> {
+        let letters = self.letters.iter().collect::<String>();
+
+        let mut filtered = self.words.fitting_letters(&letters).to_vec();
+        tracing::debug!("{} words found", filtered.len());
+
+        filtered.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        filtered.dedup();
+
+        let final_list = filtered
+            .iter()
+            .take(self.max)
+            .cloned()
+            .inspect(|w| {
+                self.count_solution(w.len());
+            })
+            .collect::<Vec<String>>();
+
+        self.solutions = final_list;
+
+        Ok(self)
+    }
+
+    pub fn count_solution(&mut self, word_length: usize) -> &mut Self {
+        self.distribution.count(word_length);
+        self
+    }
+
+    pub fn word_source_string(&self) -> String {
+        self.words.source_string()
+    }
+
+    pub fn distribution_string(&self) -> String {
+        let mut s = String::new();
+        for d in self.distribution.lengths().into_iter().rev() {
+            s.push_str(&format!(
+                "  - {:3.0} solutions with {:2.0} letters\n",
+                d.1, d.0
+            ));
+        }
+        s
+    }
+
+    pub fn solutions_title(&self) -> String {
+        let intro = "Longest words from the letters ";
+        let mut ul = String::new();
+        for _ in 0..(intro.len() + self.letters.len()) {
+            ul.push('‾');
+        }
+
+        let summary = format!(
+            "{}{}",
+            intro.yellow().bold(),
+            self.letters.iter().collect::<String>().blue().bold()
+        );
+        format!("{}\n{}", summary, ul.bold().yellow())
+    }
+
+    pub fn solutions_string(&self) -> String {
+        let mut s = String::new();
+        let mut word_length = 0;
+
+        for solution in self.solutions.iter() {
+            if solution.len() != word_length {
+                if word_length != 0 {
+                    s.push('\n');
+                }
+                word_length = solution.len();
+                s.push_str("  ");
+                s.push_str(
+                    &format!(
+                        "{} Solutions with {} letters.",
+                        self.distribution.get(word_length),
+                        word_length
+                    )
+                    .underlined()
+                    .yellow()
+                    .to_string(),
+                );
+                s.push_str("\n\n");
+            }
+            s.push_str(&format!("    {solution}\n"));
+        }
+        s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_longest_words_respect_letter_counts() {
+        let words = WordList::from_words([
+            "retain",
+            "retina",
+            "trainee",
+            "tea",
+            "teeth",
+            "rat",
+            "entertain",
+        ]);
+
+        let mut round = LettersRound::new("eratinsxb", &words).unwrap();
+        round.set_max_solutions(3).find_solutions().unwrap();
+
+        assert_eq!(round.solutions, vec!["retain", "retina", "rat"]);
+    }
+
+    #[test]
+    fn test_letters_round_needs_nine_letters() {
+        let words = WordList::default();
+        let round = LettersRound::new("abcdefgh", &words);
+        assert_eq!(
+            round.err(),
+            Some(Error::CountdownLetters("abcdefgh".to_string()))
+        );
+        let round = LettersRound::new("abcdéfgh", &words);
+        assert_eq!(
+            round.err(),
+            Some(Error::CountdownLetters("abcdéfgh".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_letters_round_has_no_blanks() {
+        let words = WordList::from_words(["retain"]);
+        assert_eq!(
+            LettersRound::new("retai?sxb", &words).err(),
+            Some(Error::CountdownLetters("retai?sxb".to_string()))
+        );
+    }
+}
@@ -0,0 +1,354 @@
+use std::fmt::Display;
+
+use colorful::Colorful;
+
+use crate::Error;
+
+const NUMBERS_ROUND_LENGTH: usize = 6;
+const MINIMUM_TARGET: u32 = 100;
+const MAXIMUM_TARGET: u32 = 999;
+const DEFAULT_LIMIT: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+impl Operation {
+    /// Apply the operation to the pair, returning `None` when the result is
+    /// not a positive integer or would only reproduce one of the inputs.
+    fn apply(&self, left: u32, right: u32) -> Option<u32> {
+        match self {
+            Operation::Add => left.checked_add(right),
+            Operation::Subtract => {
+                if left > right && left - right != right {
+                    Some(left - right)
+                } else {
+                    None
+                }
+            }
+            Operation::Multiply => {
+                if right > 1 {
+                    left.checked_mul(right)
+                } else {
+                    None
+                }
+            }
+            Operation::Divide => {
+                if right > 1 && left % right == 0 && left / right != right {
+                    Some(left / right)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Operation::Add => write!(f, "+"),
+            Operation::Subtract => write!(f, "−"),
+            Operation::Multiply => write!(f, "×"),
+            Operation::Divide => write!(f, "÷"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    pub left: u32,
+    pub operation: Operation,
+    pub right: u32,
+    pub result: u32,
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} {} = {}",
+            self.left, self.operation, self.right, self.result
+        )
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct NumbersRound {
+    numbers: Vec<u32>,
+    target: u32,
+    limit: usize,
+    best_value: Option<u32>,
+    best_operations: usize,
+    solutions: Vec<Vec<Step>>,
+}
+
+impl NumbersRound {
+    pub fn new(numbers: &[u32], target: u32) -> Result<Self, Error> {
+        if numbers.len() != NUMBERS_ROUND_LENGTH {
+            return Err(Error::CountdownNumbers(numbers.len()));
+        }
+        if !(MINIMUM_TARGET..=MAXIMUM_TARGET).contains(&target) {
+            return Err(Error::CountdownTarget(target));
+        }
+
+        Ok(Self {
+            numbers: Vec::from(numbers),
+            target,
+            limit: DEFAULT_LIMIT,
+            ..Default::default()
+        })
+    }
+
+    pub fn set_limit(&mut self, value: usize) -> &mut Self {
+        self.limit = value;
+        self
+    }
+
+    /// Search every combination of the numbers for the value closest to the
+    /// target, keeping the solutions that use the fewest operations.
+    #[tracing::instrument(skip(self))]
+    pub fn find_solutions(&mut self) -> Result<&mut Self, Error> {
+        self.best_value = None;
+        self.solutions.clear();
+
+        for number in self.numbers.clone() {
+            self.record(number, &[]);
+        }
+
+        let pool = self.numbers.clone();
+        let mut steps = Vec::new();
+        self.search(&pool, &mut steps);
+
+        tracing::info!(
+            "Best value {:?} found with {} solutions",
+            self.best_value,
+            self.solutions.len()
+        );
+        Ok(self)
+    }
+
+    pub fn best_value(&self) -> Option<u32> {
+        self.best_value
+    }
+
+    pub fn solutions(&self) -> &[Vec<Step>] {
+        &self.solutions
+    }
+
+    fn distance(&self, value: u32) -> u32 {
+        value.abs_diff(self.target)
+    }
+
+    fn search(&mut self, pool: &[u32], steps: &mut Vec<Step>) {
+        if pool.len() < 2 {
+            return;
+        }
+        // Once the target has been hit there is no point in looking for
+        // longer solutions.
+        if self.best_value == Some(self.target) && steps.len() >= self.best_operations {
+            return;
+        }
+
+        for i in 0..pool.len() {
+            for j in (i + 1)..pool.len() {
+                let (left, right) = if pool[i] >= pool[j] {
+                    (pool[i], pool[j])
+                } else {
+                    (pool[j], pool[i])
+                };
+
+                for operation in [
+                    Operation::Add,
+                    Operation::Subtract,
+                    Operation::Multiply,
+                    Operation::Divide,
+                ] {
+                    let Some(result) = operation.apply(left, right) else {
+                        continue;
+                    };
+
+                    steps.push(Step {
+                        left,
+                        operation,
+                        right,
+                        result,
+                    });
+                    self.record(result, steps);
+
+                    let mut next_pool = pool
+                        .iter()
+                        .enumerate()
+                        .filter(|(k, _)| *k != i && *k != j)
+                        .map(|(_, n)| *n)
+                        .collect::<Vec<u32>>();
+                    next_pool.push(result);
+                    self.search(&next_pool, steps);
+
+                    steps.pop();
+                }
+            }
+        }
+    }
+
+    fn record(&mut self, value: u32, steps: &[Step]) {
+        let distance = self.distance(value);
+        let better = match self.best_value {
+            None => true,
+            Some(best) => {
+                let best_distance = self.distance(best);
+                distance < best_distance
+                    || (distance == best_distance && steps.len() < self.best_operations)
+            }
+        };
+
+        if better {
+            self.best_value = Some(value);
+            self.best_operations = steps.len();
+            self.solutions = vec![steps.to_vec()];
+            return;
+        }
+
+        if self.best_value.map(|best| self.distance(best)) == Some(distance)
+            && steps.len() == self.best_operations
+            && self.solutions.len() < self.limit
+        {
+            let key = solution_key(steps);
+            if !self.solutions.iter().any(|s| solution_key(s) == key) {
+                self.solutions.push(steps.to_vec());
+            }
+        }
+    }
+
+    pub fn solutions_title(&self) -> String {
+        let intro = "Target ";
+        let numbers = self
+            .numbers
+            .iter()
+            .map(|n| n.to_string())
+            .collect::<Vec<String>>()
+            .join(" ");
+        let detail = format!("{} from {}", self.target, numbers);
+        let mut ul = String::new();
+        for _ in 0..(intro.len() + detail.chars().count()) {
+            ul.push('‾');
+        }
+
+        let summary = format!("{}{}", intro.yellow().bold(), detail.blue().bold());
+        format!("{}\n{}", summary, ul.bold().yellow())
+    }
+
+    pub fn solutions_string(&self) -> String {
+        let mut s = String::new();
+        let Some(best) = self.best_value else {
+            return s;
+        };
+
+        let heading = if best == self.target {
+            format!(
+                "{} exact solutions with {} operations.",
+                self.solutions.len(),
+                self.best_operations
+            )
+        } else {
+            format!(
+                "{} solutions reaching {} ({} away) with {} operations.",
+                self.solutions.len(),
+                best,
+                self.distance(best),
+                self.best_operations
+            )
+        };
+        s.push_str("  ");
+        s.push_str(&heading.underlined().yellow().to_string());
+        s.push_str("\n\n");
+
+        for solution in self.solutions.iter() {
+            if solution.is_empty() {
+                s.push_str(&format!("    {best}\n"));
+            }
+            for step in solution {
+                s.push_str(&format!("    {step}\n"));
+            }
+            s.push('\n');
+        }
+        s
+    }
+}
+
+fn solution_key(steps: &[Step]) -> Vec<String> {
+    let mut key = steps.iter().map(|s| s.to_string()).collect::<Vec<String>>();
+    key.sort();
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn replay(numbers: &[u32], steps: &[Step]) -> u32 {
+        let mut pool = numbers.to_vec();
+        let mut last = 0;
+        for step in steps {
+            let l = pool.iter().position(|n| *n == step.left).unwrap();
+            pool.remove(l);
+            let r = pool.iter().position(|n| *n == step.right).unwrap();
+            pool.remove(r);
+            let result = step.operation.apply(step.left, step.right).unwrap();
+            assert_eq!(result, step.result);
+            pool.push(result);
+            last = result;
+        }
+        last
+    }
+
+    #[test]
+    fn test_exact_solution() {
+        let numbers = [100, 75, 50, 25, 6, 3];
+        let mut round = NumbersRound::new(&numbers, 952).unwrap();
+        round.find_solutions().unwrap();
+
+        assert_eq!(round.best_value(), Some(952));
+        assert!(!round.solutions().is_empty());
+        for solution in round.solutions() {
+            assert_eq!(replay(&numbers, solution), 952);
+        }
+    }
+
+    #[test]
+    fn test_fewest_operations() {
+        let numbers = [100, 2, 1, 1, 1, 1];
+        let mut round = NumbersRound::new(&numbers, 200).unwrap();
+        round.find_solutions().unwrap();
+
+        assert_eq!(round.best_value(), Some(200));
+        assert_eq!(round.solutions().len(), 1);
+        assert_eq!(round.solutions()[0].len(), 1);
+        assert_eq!(round.solutions()[0][0].to_string(), "100 × 2 = 200");
+    }
+
+    #[test]
+    fn test_nearest_solution() {
+        let numbers = [1, 1, 1, 1, 1, 1];
+        let mut round = NumbersRound::new(&numbers, 100).unwrap();
+        round.find_solutions().unwrap();
+
+        assert_eq!(round.best_value(), Some(9));
+    }
+
+    #[test]
+    fn test_numbers_round_validation() {
+        assert_eq!(
+            NumbersRound::new(&[1, 2, 3], 100).err(),
+            Some(Error::CountdownNumbers(3))
+        );
+        assert_eq!(
+            NumbersRound::new(&[1, 2, 3, 4, 5, 6], 1000).err(),
+            Some(Error::CountdownTarget(1000))
+        );
+    }
+}
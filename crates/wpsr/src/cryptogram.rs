@@ -0,0 +1,411 @@
+use std::collections::{HashMap, HashSet};
+
+use colorful::Colorful;
+
+use crate::{Error, WordList};
+
+const CIPHER_ALPHABET: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const SEARCH_LIMIT: usize = 50_000_000;
+
+/// The letter pattern of a word, numbering each distinct letter in order of
+/// first appearance so that `XYZX` and `that` both become `[0, 1, 2, 0]`.
+pub fn word_pattern(word: &str) -> Vec<u8> {
+    pattern_of(&word.chars().collect::<Vec<char>>())
+}
+
+/// The pattern of any sequence of symbols, such as the numbers of a codeword
+/// entry.
+pub(crate) fn pattern_of<T: PartialEq>(symbols: &[T]) -> Vec<u8> {
+    let mut seen: Vec<&T> = Vec::new();
+    symbols
+        .iter()
+        .map(|c| match seen.iter().position(|s| *s == c) {
+            Some(i) => i as u8,
+            None => {
+                seen.push(c);
+                (seen.len() - 1) as u8
+            }
+        })
+        .collect()
+}
+
+/// Words of the list grouped by their letter pattern.
+#[derive(Debug, Default)]
+pub(crate) struct PatternIndex {
+    patterns: HashMap<Vec<u8>, Vec<String>>,
+}
+
+impl PatternIndex {
+    pub(crate) fn new<'a>(words: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut patterns: HashMap<Vec<u8>, Vec<String>> = HashMap::new();
+        let mut seen = HashSet::new();
+        for word in words {
+            let word = word.to_lowercase();
+            if word.chars().all(|c| c.is_ascii_lowercase()) && seen.insert(word.clone()) {
+                patterns.entry(word_pattern(&word)).or_default().push(word);
+            }
+        }
+        Self { patterns }
+    }
+
+    pub(crate) fn matches(&self, pattern: &[u8]) -> &[String] {
+        self.patterns.get(pattern).map_or(&[], |w| w.as_slice())
+    }
+}
+
+/// Mapping from cipher letters to plain letters, kept consistent in both
+/// directions so that no two cipher letters share a plain letter.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Key {
+    cipher_to_plain: [Option<char>; 26],
+    plain_to_cipher: [Option<char>; 26],
+}
+
+impl Key {
+    pub fn get(&self, cipher: char) -> Option<char> {
+        slot(cipher).and_then(|i| self.cipher_to_plain[i])
+    }
+
+    /// Add the pair, failing if it clashes with an existing mapping.
+    pub fn insert(&mut self, cipher: char, plain: char) -> bool {
+        let (Some(c), Some(p)) = (slot(cipher), slot(plain)) else {
+            return false;
+        };
+        match (self.cipher_to_plain[c], self.plain_to_cipher[p]) {
+            (Some(known), _) => known == plain,
+            (None, Some(_)) => false,
+            (None, None) => {
+                self.cipher_to_plain[c] = Some(plain);
+                self.plain_to_cipher[p] = Some(cipher);
+                true
+            }
+        }
+    }
+
+    /// Extend the key with a cipher word read as the plain word.
+    fn extend(&self, cipher: &str, plain: &str) -> Option<Key> {
+        let mut key = *self;
+        for (c, p) in cipher.chars().zip(plain.chars()) {
+            if !key.insert(c, p) {
+                return None;
+            }
+        }
+        Some(key)
+    }
+
+    pub fn decrypt(&self, ciphertext: &str) -> String {
+        ciphertext
+            .chars()
+            .map(|c| {
+                if c.is_ascii_uppercase() {
+                    self.get(c).unwrap_or('_')
+                } else {
+                    c
+                }
+            })
+            .collect()
+    }
+
+    /// The key as two lines, the cipher alphabet over the plain letters, with
+    /// `.` marking letters that are not yet known.
+    pub fn key_string(&self) -> String {
+        let plain = CIPHER_ALPHABET
+            .chars()
+            .map(|c| self.get(c).unwrap_or('.'))
+            .collect::<String>();
+        format!("    {CIPHER_ALPHABET}\n    {plain}")
+    }
+}
+
+fn slot(letter: char) -> Option<usize> {
+    letter
+        .is_ascii_alphabetic()
+        .then(|| (letter.to_ascii_lowercase() as u8 - b'a') as usize)
+}
+
+#[derive(Debug)]
+pub struct Cryptogram<'a> {
+    ciphertext: String,
+    words: &'a WordList,
+    known: Key,
+    max: usize,
+    solutions: Vec<Key>,
+    unsolved: Vec<String>,
+}
+
+impl<'a> Cryptogram<'a> {
+    pub fn new(ciphertext: &str, words: &'a WordList) -> Result<Self, Error> {
+        let ciphertext = ciphertext.to_ascii_uppercase();
+        if !ciphertext.chars().any(|c| c.is_ascii_uppercase()) {
+            return Err(Error::NoWordFound);
+        }
+
+        Ok(Self {
+            words,
+            ciphertext,
+            max: 5,
+            known: Key::default(),
+            solutions: Vec::new(),
+            unsolved: Vec::new(),
+        })
+    }
+
+    pub fn set_max_solutions(&mut self, value: usize) -> &mut Self {
+        self.max = value;
+        self
+    }
+
+    /// Pin known letters, given as comma separated `cipher=plain` pairs such
+    /// as `X=e,Q=t`.
+    pub fn set_known(&mut self, value: Option<String>) -> Result<&mut Self, Error> {
+        let mut known = Key::default();
+        for pair in value.iter().flat_map(|v| v.split(',')) {
+            let letters = pair
+                .split('=')
+                .map(|l| l.trim().chars().collect::<Vec<char>>())
+                .collect::<Vec<_>>();
+            let [cipher, plain] = letters.as_slice() else {
+                return Err(Error::CryptogramKey(pair.to_string()));
+            };
+            let ([cipher], [plain]) = (cipher.as_slice(), plain.as_slice()) else {
+                return Err(Error::CryptogramKey(pair.to_string()));
+            };
+            if !cipher.is_ascii_alphabetic()
+                || !plain.is_ascii_alphabetic()
+                || !known.insert(cipher.to_ascii_uppercase(), plain.to_ascii_lowercase())
+            {
+                return Err(Error::CryptogramKey(pair.to_string()));
+            }
+        }
+        self.known = known;
+        Ok(self)
+    }
+
+    pub fn solutions(&self) -> &[Key] {
+        &self.solutions
+    }
+
+    /// Search for keys that turn every cipher word into a word from the list.
+    /// Cipher words that match no word in the list are left out of the search.
+    #[tracing::instrument(skip(self))]
+    pub fn find_solutions(&mut self) -> Result<&mut Self, Error> {
+        let index = PatternIndex::new(self.words.iter());
+
+        let mut cipher_words = self
+            .ciphertext
+            .split(|c: char| !c.is_ascii_uppercase() && c != '\'')
+            .map(|w| w.replace('\'', ""))
+            .filter(|w| !w.is_empty())
+            .collect::<Vec<String>>();
+        cipher_words.sort();
+        cipher_words.dedup();
+
+        let mut searchable = Vec::new();
+        self.unsolved.clear();
+        for word in cipher_words {
+            let candidates = index
+                .matches(&word_pattern(&word))
+                .iter()
+                .filter(|plain| self.known.extend(&word, plain).is_some())
+                .cloned()
+                .collect::<Vec<String>>();
+            if candidates.is_empty() {
+                tracing::info!("No candidates for {word}");
+                self.unsolved.push(word);
+            } else {
+                searchable.push((word, candidates));
+            }
+        }
+        tracing::debug!("Searching {} cipher words", searchable.len());
+
+        let mut solutions = Vec::new();
+        let mut budget = SEARCH_LIMIT;
+        search(
+            searchable,
+            self.known,
+            &mut solutions,
+            self.max,
+            &mut budget,
+        );
+        if budget == 0 {
+            tracing::warn!("Search stopped after {SEARCH_LIMIT} candidate checks");
+        }
+
+        self.solutions = solutions;
+        Ok(self)
+    }
+
+    pub fn word_source_string(&self) -> String {
+        self.words.source_string()
+    }
+
+    pub fn solutions_title(&self) -> String {
+        let intro = "Decryptions of ";
+        let mut ul = String::new();
+        for _ in 0..(intro.len() + self.ciphertext.chars().count()) {
+            ul.push('‾');
+        }
+
+        let summary = format!(
+            "{}{}",
+            intro.yellow().bold(),
+            self.ciphertext.clone().blue().bold()
+        );
+        format!("{}\n{}", summary, ul.bold().yellow())
+    }
+
+    pub fn solutions_string(&self) -> String {
+        let mut s = String::new();
+
+        s.push_str("  ");
+        s.push_str(
+            &format!("{} consistent keys found.", self.solutions.len())
+                .underlined()
+                .yellow()
+                .to_string(),
+        );
+        s.push_str("\n\n");
+
+        if self.solutions.is_empty() {
+            s.push_str(&format!("    {}\n\n", self.known.decrypt(&self.ciphertext)));
+            s.push_str(&self.known.key_string());
+            s.push('\n');
+        }
+        for key in self.solutions.iter() {
+            s.push_str(&format!("    {}\n\n", key.decrypt(&self.ciphertext)));
+            s.push_str(&key.key_string());
+            s.push_str("\n\n");
+        }
+
+        if !self.unsolved.is_empty() {
+            s.push_str(&format!(
+                "  No words in the list match: {}\n",
+                self.unsolved.join(", ")
+            ));
+        }
+        s
+    }
+}
+
+/// Backtracking search that always expands the cipher word with the fewest
+/// remaining candidates and drops candidates the new key rules out.
+fn search(
+    words: Vec<(String, Vec<String>)>,
+    key: Key,
+    solutions: &mut Vec<Key>,
+    max: usize,
+    budget: &mut usize,
+) {
+    if solutions.len() >= max || *budget == 0 {
+        return;
+    }
+
+    let Some(next) = (0..words.len()).min_by_key(|i| words[*i].1.len()) else {
+        if !solutions.contains(&key) {
+            solutions.push(key);
+        }
+        return;
+    };
+    let mut rest = words;
+    let (cipher, candidates) = rest.swap_remove(next);
+
+    for plain in candidates.iter() {
+        let Some(extended) = key.extend(&cipher, plain) else {
+            continue;
+        };
+
+        let mut remaining = Vec::with_capacity(rest.len());
+        for (word, word_candidates) in rest.iter() {
+            *budget = budget.saturating_sub(word_candidates.len());
+            let word_candidates = word_candidates
+                .iter()
+                .filter(|p| extended.extend(word, p).is_some())
+                .cloned()
+                .collect::<Vec<String>>();
+            if word_candidates.is_empty() {
+                break;
+            }
+            remaining.push((word.clone(), word_candidates));
+        }
+
+        if remaining.len() == rest.len() {
+            search(remaining, extended, solutions, max, budget);
+        }
+        if solutions.len() >= max || *budget == 0 {
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_word_pattern() {
+        assert_eq!(word_pattern("XYZX"), vec![0, 1, 2, 0]);
+        assert_eq!(word_pattern("that"), word_pattern("XYZX"));
+        assert_ne!(word_pattern("this"), word_pattern("that"));
+        assert_eq!(word_pattern("test"), word_pattern("that"));
+    }
+
+    #[test]
+    fn test_solve_cryptogram() {
+        let words = WordList::from_words([
+            "that", "this", "is", "as", "the", "test", "tent", "to", "it",
+        ]);
+        let mut cryptogram = Cryptogram::new("QWEQ RT QWY QYTQ.", &words).unwrap();
+        cryptogram.find_solutions().unwrap();
+
+        assert_eq!(cryptogram.solutions().len(), 1);
+        assert_eq!(
+            cryptogram.solutions()[0].decrypt("QWEQ RT QWY QYTQ."),
+            "that is the test."
+        );
+    }
+
+    #[test]
+    fn test_known_letters_restrict_the_key() {
+        let words = WordList::from_words([
+            "that", "this", "is", "as", "the", "test", "tent", "to", "it",
+        ]);
+        let mut cryptogram = Cryptogram::new("QYTQ", &words).unwrap();
+        cryptogram.find_solutions().unwrap();
+        assert_eq!(cryptogram.solutions().len(), 3);
+
+        cryptogram
+            .set_known(Some("T=n".to_string()))
+            .unwrap()
+            .find_solutions()
+            .unwrap();
+        assert_eq!(cryptogram.solutions().len(), 1);
+        assert_eq!(cryptogram.solutions()[0].decrypt("QYTQ"), "tent");
+    }
+
+    #[test]
+    fn test_invalid_known_letters() {
+        let words = WordList::from_words([
+            "that", "this", "is", "as", "the", "test", "tent", "to", "it",
+        ]);
+        let mut cryptogram = Cryptogram::new("QYTQ", &words).unwrap();
+        assert_eq!(
+            cryptogram.set_known(Some("T=n,Q=n".to_string())).err(),
+            Some(Error::CryptogramKey("Q=n".to_string()))
+        );
+        assert_eq!(
+            cryptogram.set_known(Some("TQ=n".to_string())).err(),
+            Some(Error::CryptogramKey("TQ=n".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_partial_key() {
+        let mut key = Key::default();
+        key.insert('B', 'a');
+        assert_eq!(
+            key.key_string(),
+            "    ABCDEFGHIJKLMNOPQRSTUVWXYZ\n    .a........................"
+        );
+        assert_eq!(key.decrypt("BC B"), "a_ a");
+    }
+}
@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+
+/// Count of words found at each word length.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Distribution {
+    counts: HashMap<usize, i32>,
+}
+
+impl Distribution {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add one word of the given length to the count.
+    pub fn count(&mut self, length: usize) -> &mut Self {
+        *self.counts.entry(length).or_insert(0) += 1;
+        self
+    }
+
+    /// Number of words counted with the given length.
+    pub fn get(&self, length: usize) -> i32 {
+        self.counts.get(&length).copied().unwrap_or(0)
+    }
+
+    /// Number of words counted at every length.
+    pub fn total(&self) -> i32 {
+        self.counts.values().sum()
+    }
+
+    /// Lengths and their counts, shortest first.
+    pub fn lengths(&self) -> Vec<(usize, i32)> {
+        let mut lengths = self
+            .counts
+            .iter()
+            .map(|(l, c)| (*l, *c))
+            .collect::<Vec<_>>();
+        lengths.sort_by_key(|a| a.0);
+        lengths
+    }
+}
+
+impl FromIterator<usize> for Distribution {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut distribution = Self::new();
+        for length in iter {
+            distribution.count(length);
+        }
+        distribution
+    }
+}
@@ -6,6 +6,16 @@ pub enum Error {
     NoWordFound,
     #[error("No word list provided")]
     NoWordList,
+    #[error("Failed to read word list `{0}`: {1}.")]
+    WordListRead(String, String),
+    #[error("Failed to write word list `{0}`: {1}.")]
+    WordListWrite(String, String),
+    #[error("Invalid word list: {0}.")]
+    WordListFormat(String),
+    #[error("Word list `{0}` has no word frequencies.")]
+    NoFrequencies(String),
+    #[error("Word list `{0}` has {1} problems.")]
+    WordListProblems(String, usize),
     #[error("String must be exactly 9 to 24 letters. Letters Provided: `{}`.", 0)]
     TooFewOrManyLetters(usize),
     #[error("String must be divisible by 3. Letters Provided: `{}`.", 0)]
@@ -20,4 +30,92 @@ pub enum Error {
     FailedToBuildWordChain,
     #[error("Chain too long.")]
     ChainTooLong,
+    #[error("Failed to read or write the boxed archive: {0}.")]
+    BoxedArchive(String),
+    #[error("No archived puzzle `{0}`.")]
+    BoxedArchiveEntry(String),
+    #[error("{0} archived puzzles gave different results on replay.")]
+    BoxedReplay(usize),
+    #[error("The letters round needs exactly 9 letters. Letters provided: `{0}`.")]
+    CountdownLetters(String),
+    #[error("The numbers round needs exactly 6 numbers. Numbers provided: `{0}`.")]
+    CountdownNumbers(usize),
+    #[error("The target must be a three-digit number. Target provided: `{0}`.")]
+    CountdownTarget(u32),
+    #[error("A rack must hold 1 to 7 tiles. Tiles provided: `{0}`.")]
+    ScrabbleRack(usize),
+    #[error("Unknown square on the board: `{0}`.")]
+    ScrabbleBoard(char),
+    #[error("Grid rows must be letters of equal length. Grid provided: `{0}`.")]
+    BoggleGrid(String),
+    #[error("Dice sets roll a 4x4 board. Board requested: `{0}x{1}`.")]
+    BoggleDiceSize(usize, usize),
+    #[error("No board met the word density after {0} attempts.")]
+    BoggleBoardNotFound(usize),
+    #[error("Start and end words must be the same length: `{0}` and `{1}`.")]
+    LadderLength(String, String),
+    #[error("No ladder found from `{0}` to `{1}`.")]
+    NoLadderFound(String, String),
+    #[error("Ladder word `{0}` is not in the word list.")]
+    LadderWord(String),
+    #[error("Known letters must be given as `cipher=plain` pairs. Pair provided: `{0}`.")]
+    CryptogramKey(String),
+    #[error(
+        "Codeword cells must be numbers 1 to 26 or `#` in rows of equal length. Cell provided: `{0}`."
+    )]
+    CodewordGrid(String),
+    #[error(
+        "A spelling bee needs 7 different letters with the centre first. Letters provided: `{0}`."
+    )]
+    BeeLetters(String),
+    #[error("`{0}` is not an answer to this spelling bee.")]
+    BeeWord(String),
+    #[error("Failed to read or write the found words: {0}.")]
+    BeeState(String),
+    #[error("Known letters must be given as `number=letter` pairs. Pair provided: `{0}`.")]
+    CodewordKey(String),
+    #[error("No words fit the codeword grid.")]
+    CodewordUnsolved,
+    #[error(
+        "Templates need rows of equal length using `#`, `.` or letters. Template provided: `{0}`."
+    )]
+    FillTemplate(String),
+    #[error("No words from the list fill every slot in the template.")]
+    FillUnsolved,
+    #[error("Grid rows must be letters of equal length. Grid provided: `{0}`.")]
+    WordSearchGrid(String),
+    #[error("Fill letters must be letters a to z. Letters provided: `{0}`.")]
+    WordSearchFill(String),
+    #[error("Patterns must be letters with `_` for unknown letters. Pattern provided: `{0}`.")]
+    HangmanPattern(String),
+    #[error("Wrong guesses must be letters missing from the pattern. Guess provided: `{0}`.")]
+    HangmanGuess(char),
+    #[error(
+        "Clues must be letters with optional circled positions, e.g. `nagle:1,3`. Clue provided: `{0}`."
+    )]
+    JumbleClue(String),
+    #[error("Answers must be groups of `_`, e.g. `___ _____`. Answer provided: `{0}`.")]
+    JumbleAnswer(String),
+    #[error("No word unscrambles `{0}`.")]
+    JumbleUnsolved(String),
+    #[error("A word wheel needs exactly 9 letters. Letters provided: `{0}`.")]
+    WheelLetters(String),
+    #[error("The centre letter must be one of the wheel letters. Letter provided: `{0}`.")]
+    WheelCentre(char),
+    #[error("A text twist needs 6 or 7 letters. Letters provided: `{0}`.")]
+    TwistLetters(String),
+    #[error("Guesses must be letters of the same length. Guess provided: `{0}`.")]
+    WordleGuess(String),
+    #[error("Marks must be g, y or b for each letter of each guess. Marks provided: `{0}`.")]
+    WordleFeedback(String),
+    #[error("No word fits the marks `{0}`.")]
+    WordleNoCandidates(String),
+    #[error("A waffle grid needs 21 letters, or 25 with the holes. Grid provided: `{0}`.")]
+    WaffleGrid(String),
+    #[error("Waffle colours need g, y or b for each of the 21 tiles. Colours provided: `{0}`.")]
+    WaffleColours(String),
+    #[error("No words rearrange the tiles to match the colours.")]
+    WaffleUnsolved,
+    #[error("The tiles cannot be swapped into the solution.")]
+    WaffleSwaps,
 }
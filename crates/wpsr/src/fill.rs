@@ -0,0 +1,536 @@
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+
+use colorful::Colorful;
+use rand::{SeedableRng, seq::SliceRandom};
+use rand_chacha::ChaCha20Rng;
+
+use crate::{Error, Trie, WordList};
+
+const SEARCH_LIMIT: usize = 200_000;
+
+/// A crossword grid of blocks and open cells, some of which may already hold
+/// a letter.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Template {
+    rows: usize,
+    columns: usize,
+    blocks: Vec<bool>,
+    letters: Vec<Option<char>>,
+}
+
+impl Template {
+    /// Parse a template given one row per line or with rows separated by `/`,
+    /// using `#` for blocks, `.` or `_` for empty cells and letters for cells
+    /// that are already filled.
+    pub fn parse(text: &str) -> Result<Self, Error> {
+        let rows = text
+            .split(['\n', '/'])
+            .map(|r| r.split_whitespace().collect::<String>())
+            .filter(|r| !r.is_empty())
+            .collect::<Vec<String>>();
+
+        let columns = rows.first().map_or(0, |r| r.chars().count());
+        if columns == 0 || rows.iter().any(|r| r.chars().count() != columns) {
+            return Err(Error::FillTemplate(text.to_string()));
+        }
+
+        let mut blocks = Vec::new();
+        let mut letters = Vec::new();
+        for c in rows.iter().flat_map(|r| r.chars()) {
+            match c {
+                '#' => {
+                    blocks.push(true);
+                    letters.push(None);
+                }
+                '.' | '_' => {
+                    blocks.push(false);
+                    letters.push(None);
+                }
+                c if c.is_ascii_alphabetic() => {
+                    blocks.push(false);
+                    letters.push(Some(c.to_ascii_lowercase()));
+                }
+                _ => return Err(Error::FillTemplate(text.to_string())),
+            }
+        }
+
+        Ok(Self {
+            rows: rows.len(),
+            columns,
+            blocks,
+            letters,
+        })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Every run of two or more open cells across and down, numbered in
+    /// reading order as in a printed crossword.
+    pub fn slots(&self) -> Vec<Slot> {
+        let open = |r: usize, c: usize| {
+            r < self.rows && c < self.columns && !self.blocks[r * self.columns + c]
+        };
+
+        let mut slots = Vec::new();
+        let mut number = 0;
+        for r in 0..self.rows {
+            for c in 0..self.columns {
+                if !open(r, c) {
+                    continue;
+                }
+                let across = (c == 0 || !open(r, c - 1)) && open(r, c + 1);
+                let down = (r == 0 || !open(r - 1, c)) && open(r + 1, c);
+                if !across && !down {
+                    continue;
+                }
+                number += 1;
+                if across {
+                    let cells = (c..self.columns)
+                        .take_while(|cc| open(r, *cc))
+                        .map(|cc| r * self.columns + cc)
+                        .collect();
+                    slots.push(Slot {
+                        number,
+                        across: true,
+                        cells,
+                    });
+                }
+                if down {
+                    let cells = (r..self.rows)
+                        .take_while(|rr| open(*rr, c))
+                        .map(|rr| rr * self.columns + c)
+                        .collect();
+                    slots.push(Slot {
+                        number,
+                        across: false,
+                        cells,
+                    });
+                }
+            }
+        }
+        slots
+    }
+}
+
+/// An across or down entry in the grid and the cells it covers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Slot {
+    pub number: usize,
+    pub across: bool,
+    pub cells: Vec<usize>,
+}
+
+/// A crossing between two slots: the position in this slot, the other slot
+/// and the position in that slot.
+type Crossing = (usize, usize, usize);
+
+#[derive(Debug)]
+pub struct Fill<'a> {
+    template: Template,
+    words: &'a WordList,
+    seed: Option<u64>,
+    slots: Vec<Slot>,
+    answers: Vec<String>,
+}
+
+impl<'a> Fill<'a> {
+    pub fn new(template: &str, words: &'a WordList) -> Result<Self, Error> {
+        let template = Template::parse(template)?;
+        Ok(Self {
+            words,
+            slots: template.slots(),
+            template,
+            seed: None,
+            answers: Vec::new(),
+        })
+    }
+
+    pub fn set_seed(&mut self, value: Option<u64>) -> &mut Self {
+        self.seed = value;
+        self
+    }
+
+    pub fn slots(&self) -> &[Slot] {
+        &self.slots
+    }
+
+    /// The word placed in each slot, in the same order as `slots`.
+    pub fn answers(&self) -> &[String] {
+        &self.answers
+    }
+
+    /// Fill every slot with a different word from the list. Candidates for
+    /// each slot are kept arc consistent with the slots that cross it, and the
+    /// search always guesses in the slot with the fewest candidates left.
+    #[tracing::instrument(skip(self))]
+    pub fn fill(&mut self) -> Result<&mut Self, Error> {
+        let mut rng = match self.seed {
+            Some(seed) => ChaCha20Rng::seed_from_u64(seed),
+            None => ChaCha20Rng::from_rng(&mut rand::rng()),
+        };
+
+        let mut seen = HashSet::new();
+        // Lengths are shuffled in order so the same seed gives the same fill.
+        let mut shuffled: BTreeMap<usize, Vec<String>> = BTreeMap::new();
+        for word in self.words.iter() {
+            let word = word.to_lowercase();
+            if word.chars().all(|c| c.is_ascii_lowercase()) && seen.insert(word.clone()) {
+                shuffled.entry(word.len()).or_default().push(word);
+            }
+        }
+        for words in shuffled.values_mut() {
+            words.shuffle(&mut rng);
+        }
+
+        // A trie for each length finds the candidates for a slot without
+        // trying words that break on the first letter the template fixes.
+        let indexes = shuffled
+            .iter()
+            .map(|(length, words)| (*length, Trie::from_words(words)))
+            .collect::<HashMap<usize, Trie>>();
+        let lexicon = shuffled
+            .into_iter()
+            .map(|(length, words)| {
+                let words = words
+                    .iter()
+                    .map(|w| w.bytes().map(|b| b - b'a').collect())
+                    .collect();
+                (length, words)
+            })
+            .collect::<HashMap<usize, Vec<Vec<u8>>>>();
+
+        let mut crossings: Vec<Vec<Crossing>> = vec![Vec::new(); self.slots.len()];
+        for (s, slot) in self.slots.iter().enumerate() {
+            for (o, other) in self.slots.iter().enumerate() {
+                if s == o {
+                    continue;
+                }
+                for (i, cell) in slot.cells.iter().enumerate() {
+                    if let Some(j) = other.cells.iter().position(|c| c == cell) {
+                        crossings[s].push((i, o, j));
+                    }
+                }
+            }
+        }
+
+        let search = Search {
+            slots: &self.slots,
+            lexicon: &lexicon,
+            crossings: &crossings,
+        };
+
+        let mut domains = Vec::new();
+        for slot in self.slots.iter() {
+            let pattern = slot
+                .cells
+                .iter()
+                .map(|cell| self.template.letters[*cell])
+                .collect::<Vec<Option<char>>>();
+            let domain = indexes
+                .get(&slot.cells.len())
+                .map_or_else(Vec::new, |index| index.matching(&pattern));
+            tracing::debug!("Slot {} has {} candidates", slot.number, domain.len());
+            domains.push(domain);
+        }
+
+        let mut budget = SEARCH_LIMIT;
+        let queue = (0..self.slots.len()).collect();
+        let Some(domains) = search.solve(domains, queue, &mut budget) else {
+            if budget == 0 {
+                tracing::warn!("Search stopped after {SEARCH_LIMIT} guesses");
+            }
+            return Err(Error::FillUnsolved);
+        };
+
+        self.answers = self
+            .slots
+            .iter()
+            .zip(domains.iter())
+            .map(|(slot, domain)| {
+                lexicon[&slot.cells.len()][domain[0]]
+                    .iter()
+                    .map(|l| (b'a' + l) as char)
+                    .collect()
+            })
+            .collect();
+
+        Ok(self)
+    }
+
+    pub fn word_source_string(&self) -> String {
+        self.words.source_string()
+    }
+
+    pub fn solutions_title(&self) -> String {
+        let intro = "Crossword fill for a grid of ";
+        let size = format!("{}×{}", self.template.rows, self.template.columns);
+        let mut ul = String::new();
+        for _ in 0..(intro.len() + size.chars().count()) {
+            ul.push('‾');
+        }
+
+        let summary = format!("{}{}", intro.yellow().bold(), size.blue().bold());
+        format!("{}\n{}", summary, ul.bold().yellow())
+    }
+
+    /// The filled grid followed by the across and down entries.
+    pub fn solutions_string(&self) -> String {
+        let mut letters = self.template.letters.clone();
+        for (slot, answer) in self.slots.iter().zip(self.answers.iter()) {
+            for (cell, letter) in slot.cells.iter().zip(answer.chars()) {
+                letters[*cell] = Some(letter);
+            }
+        }
+
+        let mut s = String::new();
+        for r in 0..self.template.rows {
+            let row = (0..self.template.columns)
+                .map(|c| {
+                    let cell = r * self.template.columns + c;
+                    if self.template.blocks[cell] {
+                        '#'
+                    } else {
+                        letters[cell].unwrap_or('.').to_ascii_uppercase()
+                    }
+                })
+                .map(|c| c.to_string())
+                .collect::<Vec<String>>()
+                .join(" ");
+            s.push_str(&format!("    {row}\n"));
+        }
+
+        for (heading, across) in [("Across", true), ("Down", false)] {
+            s.push_str("\n  ");
+            s.push_str(&heading.underlined().yellow().to_string());
+            s.push('\n');
+            for (slot, answer) in self.slots.iter().zip(self.answers.iter()) {
+                if slot.across == across {
+                    s.push_str(&format!("    {:3} {answer}\n", slot.number));
+                }
+            }
+        }
+        s
+    }
+}
+
+struct Search<'a> {
+    slots: &'a [Slot],
+    lexicon: &'a HashMap<usize, Vec<Vec<u8>>>,
+    crossings: &'a [Vec<Crossing>],
+}
+
+impl Search<'_> {
+    fn word(&self, slot: usize, index: usize) -> &[u8] {
+        &self.lexicon[&self.slots[slot].cells.len()][index]
+    }
+
+    fn solve(
+        &self,
+        mut domains: Vec<Vec<usize>>,
+        queue: VecDeque<usize>,
+        budget: &mut usize,
+    ) -> Option<Vec<Vec<usize>>> {
+        if *budget == 0 {
+            return None;
+        }
+        *budget -= 1;
+        if !self.propagate(&mut domains, queue) {
+            return None;
+        }
+
+        let Some(slot) = (0..domains.len())
+            .filter(|s| domains[*s].len() > 1)
+            .min_by_key(|s| domains[*s].len())
+        else {
+            return Some(domains);
+        };
+
+        for candidate in domains[slot].clone() {
+            let mut next = domains.clone();
+            next[slot] = vec![candidate];
+            if let Some(solved) = self.solve(next, VecDeque::from([slot]), budget) {
+                return Some(solved);
+            }
+            if *budget == 0 {
+                return None;
+            }
+        }
+        None
+    }
+
+    /// AC-3 over the crossings, with each slot that is down to one word also
+    /// removing that word from every other slot of the same length. Returns
+    /// false when a slot has no candidates left.
+    fn propagate(&self, domains: &mut [Vec<usize>], mut queue: VecDeque<usize>) -> bool {
+        let mut queued = vec![false; domains.len()];
+        for s in queue.iter() {
+            queued[*s] = true;
+        }
+
+        while let Some(s) = queue.pop_front() {
+            queued[s] = false;
+            if domains[s].is_empty() {
+                return false;
+            }
+
+            let mut changed = Vec::new();
+            for (i, o, j) in self.crossings[s].iter() {
+                let mut allowed = 0u32;
+                for w in domains[*o].iter() {
+                    allowed |= 1 << self.word(*o, *w)[*j];
+                }
+                // Revise the other slot against this one, since this slot is
+                // the one whose candidates changed.
+                let mut here = 0u32;
+                for w in domains[s].iter() {
+                    here |= 1 << self.word(s, *w)[*i];
+                }
+                let before = domains[*o].len();
+                domains[*o].retain(|w| here & (1 << self.word(*o, *w)[*j]) != 0);
+                if domains[*o].is_empty() {
+                    return false;
+                }
+                if domains[*o].len() < before {
+                    changed.push(*o);
+                }
+                let before = domains[s].len();
+                domains[s].retain(|w| allowed & (1 << self.word(s, *w)[*i]) != 0);
+                if domains[s].is_empty() {
+                    return false;
+                }
+                if domains[s].len() < before {
+                    changed.push(s);
+                }
+            }
+
+            if domains[s].len() == 1 {
+                let word = domains[s][0];
+                let length = self.slots[s].cells.len();
+                for (o, domain) in domains.iter_mut().enumerate() {
+                    if o != s && self.slots[o].cells.len() == length {
+                        let before = domain.len();
+                        domain.retain(|w| *w != word);
+                        if domain.is_empty() {
+                            return false;
+                        }
+                        if domain.len() < before {
+                            changed.push(o);
+                        }
+                    }
+                }
+            }
+
+            for o in changed {
+                if !queued[o] {
+                    queued[o] = true;
+                    queue.push_back(o);
+                }
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WORDS: [&str; 7] = ["cat", "wed", "cow", "ted", "bat", "bed", "bow"];
+
+    fn fill<'a>(template: &str, words: &'a WordList) -> Fill<'a> {
+        let mut fill = Fill::new(template, words).unwrap();
+        fill.set_seed(Some(1));
+        fill
+    }
+
+    #[test]
+    fn test_slots_are_numbered_in_reading_order() {
+        let template = Template::parse("...#/.#../....").unwrap();
+        let slots = template
+            .slots()
+            .iter()
+            .map(|s| (s.number, s.across, s.cells.len()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            slots,
+            vec![
+                (1, true, 3),
+                (1, false, 3),
+                (2, false, 3),
+                (3, true, 2),
+                (4, false, 2),
+                (5, true, 4),
+            ]
+        );
+        assert_eq!(
+            Template::parse("..!/..."),
+            Err(Error::FillTemplate("..!/...".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_fill_respects_letters_and_crossings() {
+        let words = WordList::from_words(WORDS);
+        let mut fill = fill("c.t/.#./...", &words);
+        fill.fill().unwrap();
+
+        let answers = fill
+            .slots()
+            .iter()
+            .zip(fill.answers())
+            .map(|(s, a)| (s.number, s.across, a.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            answers,
+            vec![
+                (1, true, "cat"),
+                (1, false, "cow"),
+                (2, false, "ted"),
+                (3, true, "wed"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fill_never_repeats_a_word() {
+        // ab/ba across would need ab/ba again down.
+        let words = WordList::from_words(["ab", "ba"]);
+        assert_eq!(
+            fill("../..", &words).fill().err(),
+            Some(Error::FillUnsolved)
+        );
+
+        let words = WordList::from_words(["no", "ex", "ne", "ox"]);
+        let mut fill = fill("../..", &words);
+        fill.fill().unwrap();
+        let mut answers = fill.answers().to_vec();
+        answers.sort();
+        answers.dedup();
+        assert_eq!(answers.len(), 4);
+    }
+
+    #[test]
+    fn test_same_seed_same_fill() {
+        let words = WordList::from_words([
+            "eat", "end", "tat", "bat", "bed", "tie", "tad", "ado", "add", "oat", "and", "one",
+            "odd", "ode", "tab", "bee", "ate", "at", "ta", "data",
+        ]);
+        let first = fill("...#/.#../....", &words)
+            .fill()
+            .unwrap()
+            .answers()
+            .to_vec();
+        for _ in 0..10 {
+            let mut again = fill("...#/.#../....", &words);
+            again.fill().unwrap();
+            assert_eq!(again.answers(), first);
+        }
+    }
+}
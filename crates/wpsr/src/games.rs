@@ -0,0 +1,7 @@
+mod jumble;
+mod twist;
+mod wheel;
+
+pub use jumble::{Clue, Jumble, JumbleAnswer};
+pub use twist::TextTwist;
+pub use wheel::WordWheel;
@@ -0,0 +1,382 @@
+use std::str::FromStr;
+
+use colorful::Colorful;
+
+use crate::{Error, WordFilters, WordList};
+
+const MAX_COMBINATIONS: usize = 50;
+
+/// A scrambled word and the positions of the circled letters in the
+/// unscrambled word.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Clue {
+    pub letters: String,
+    pub circles: Vec<usize>,
+}
+
+impl FromStr for Clue {
+    type Err = Error;
+
+    /// Parse a clue written as the letters followed by the circled positions,
+    /// counted from one, for example `nagle:1,3`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (letters, circles) = s.split_once(':').unwrap_or((s, ""));
+        let letters = letters.to_ascii_lowercase();
+        if letters.is_empty() || !letters.chars().all(|c| c.is_ascii_lowercase()) {
+            return Err(Error::JumbleClue(s.to_string()));
+        }
+
+        let mut positions = Vec::new();
+        for position in circles.split(',').filter(|p| !p.is_empty()) {
+            match position.trim().parse::<usize>() {
+                Ok(p) if p >= 1 && p <= letters.len() => positions.push(p - 1),
+                _ => return Err(Error::JumbleClue(s.to_string())),
+            }
+        }
+
+        Ok(Self {
+            letters,
+            circles: positions,
+        })
+    }
+}
+
+/// One reading of the clues with the circled letters it gives and the final
+/// answers those letters make.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JumbleAnswer {
+    pub words: Vec<String>,
+    pub circled: String,
+    pub answers: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct Jumble<'a> {
+    clues: Vec<Clue>,
+    lengths: Vec<usize>,
+    words: &'a WordList,
+    max: usize,
+    unscrambled: Vec<Vec<String>>,
+    solutions: Vec<JumbleAnswer>,
+}
+
+impl<'a> Jumble<'a> {
+    pub fn new(clues: &[String], words: &'a WordList) -> Result<Self, Error> {
+        let clues = clues
+            .iter()
+            .map(|c| c.parse::<Clue>())
+            .collect::<Result<Vec<Clue>, Error>>()?;
+        if clues.is_empty() {
+            return Err(Error::NoWordFound);
+        }
+
+        Ok(Self {
+            words,
+            clues,
+            max: 10,
+            lengths: Vec::new(),
+            unscrambled: Vec::new(),
+            solutions: Vec::new(),
+        })
+    }
+
+    /// Shape of the final answer as groups of `_`, for example `___ _____`
+    /// for a three letter word followed by a five letter word.
+    pub fn set_answer(&mut self, value: Option<String>) -> Result<&mut Self, Error> {
+        let Some(pattern) = value else {
+            self.lengths.clear();
+            return Ok(self);
+        };
+        if !pattern.chars().all(|c| c == '_' || c == ' ' || c == '-') {
+            return Err(Error::JumbleAnswer(pattern));
+        }
+        self.lengths = pattern
+            .split([' ', '-'])
+            .filter(|g| !g.is_empty())
+            .map(|g| g.len())
+            .collect();
+        Ok(self)
+    }
+
+    pub fn set_max_solutions(&mut self, value: usize) -> &mut Self {
+        self.max = value;
+        self
+    }
+
+    pub fn solutions(&self) -> &[JumbleAnswer] {
+        &self.solutions
+    }
+
+    /// Unscramble each clue, then anagram the circled letters of every
+    /// combination of unscrambled words into the final answer.
+    #[tracing::instrument(skip(self))]
+    pub fn find_solutions(&mut self) -> Result<&mut Self, Error> {
+        let lower = self.words.to_lowercase();
+        let mut words = lower
+            .iter()
+            .filter(|w| w.chars().all(|c| c.is_ascii_lowercase()))
+            .map(String::from)
+            .collect::<Vec<String>>();
+        words.sort();
+
+        // Clues are unscrambled by signature, read from the list file when it
+        // stores them.
+        let mut unscrambled = Vec::new();
+        for clue in self.clues.iter() {
+            let matches = lower.anagrams_of(&clue.letters).sorted().to_vec();
+            if matches.is_empty() {
+                return Err(Error::JumbleUnsolved(clue.letters.clone()));
+            }
+            unscrambled.push(matches);
+        }
+
+        let mut combinations = vec![Vec::new()];
+        for matches in unscrambled.iter() {
+            combinations = combinations
+                .iter()
+                .flat_map(|combination| {
+                    matches.iter().map(move |word| {
+                        let mut next: Vec<String> = combination.clone();
+                        next.push(word.clone());
+                        next
+                    })
+                })
+                .take(MAX_COMBINATIONS)
+                .collect();
+        }
+
+        let mut solutions = Vec::new();
+        for combination in combinations {
+            let circled = self
+                .clues
+                .iter()
+                .zip(combination.iter())
+                .flat_map(|(clue, word)| {
+                    clue.circles
+                        .iter()
+                        .filter_map(|p| word.chars().nth(*p))
+                        .collect::<Vec<char>>()
+                })
+                .collect::<String>();
+
+            let lengths = if self.lengths.is_empty() {
+                vec![circled.len()]
+            } else {
+                self.lengths.clone()
+            };
+            let mut answers = Vec::new();
+            if lengths.iter().sum::<usize>() == circled.len() {
+                let mut phrase = Vec::new();
+                find_phrases(
+                    &words,
+                    &circled,
+                    &lengths,
+                    &mut phrase,
+                    &mut answers,
+                    self.max,
+                );
+            }
+            tracing::debug!("{combination:?} gives {circled} with {answers:?}");
+
+            solutions.push(JumbleAnswer {
+                words: combination,
+                circled,
+                answers,
+            });
+        }
+        solutions.sort_by_key(|s| s.answers.is_empty());
+
+        self.unscrambled = unscrambled;
+        self.solutions = solutions;
+
+        Ok(self)
+    }
+
+    pub fn word_source_string(&self) -> String {
+        self.words.source_string()
+    }
+
+    pub fn solutions_title(&self) -> String {
+        let intro = "Jumble of ";
+        let clues = self
+            .clues
+            .iter()
+            .map(|c| c.letters.clone())
+            .collect::<Vec<String>>()
+            .join(" ");
+        let mut ul = String::new();
+        for _ in 0..(intro.len() + clues.len()) {
+            ul.push('‾');
+        }
+
+        let summary = format!("{}{}", intro.yellow().bold(), clues.blue().bold());
+        format!("{}\n{}", summary, ul.bold().yellow())
+    }
+
+    pub fn solutions_string(&self) -> String {
+        let mut s = String::new();
+
+        s.push_str("  ");
+        s.push_str(&"Unscrambled words".underlined().yellow().to_string());
+        s.push_str("\n\n");
+        for (clue, words) in self.clues.iter().zip(self.unscrambled.iter()) {
+            let circled = words
+                .iter()
+                .map(|w| circle_letters(w, &clue.circles))
+                .collect::<Vec<String>>()
+                .join(", ");
+            s.push_str(&format!("    {:<10} {}\n", clue.letters, circled));
+        }
+
+        for solution in self.solutions.iter() {
+            s.push_str("\n  ");
+            s.push_str(
+                &format!("Circled letters {}", solution.circled)
+                    .underlined()
+                    .yellow()
+                    .to_string(),
+            );
+            s.push_str("\n\n");
+            if self.solutions.len() > 1 {
+                s.push_str(&format!("    from {}\n", solution.words.join(" ")));
+            }
+            if solution.answers.is_empty() {
+                s.push_str("    no answer found\n");
+            }
+            for answer in solution.answers.iter() {
+                s.push_str(&format!("    {answer}\n"));
+            }
+        }
+        s
+    }
+}
+
+/// The word with its circled letters in capitals, for example `AnGle`.
+fn circle_letters(word: &str, circles: &[usize]) -> String {
+    word.chars()
+        .enumerate()
+        .map(|(i, c)| {
+            if circles.contains(&i) {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
+/// Build phrases with words of the given lengths that use every letter.
+fn find_phrases(
+    words: &[String],
+    letters: &str,
+    lengths: &[usize],
+    phrase: &mut Vec<String>,
+    answers: &mut Vec<String>,
+    max: usize,
+) {
+    if answers.len() >= max {
+        return;
+    }
+    let Some((length, rest)) = lengths.split_first() else {
+        answers.push(phrase.join(" "));
+        return;
+    };
+
+    let candidates = words
+        .iter()
+        .filter(|w| w.len() == *length)
+        .cloned()
+        .collect::<Vec<String>>()
+        .filter_includes_specific_letters_in_volume(letters);
+    for word in candidates {
+        let mut remaining = letters.to_string();
+        for letter in word.chars() {
+            if let Some(i) = remaining.find(letter) {
+                remaining.remove(i);
+            }
+        }
+        phrase.push(word);
+        find_phrases(words, &remaining, rest, phrase, answers, max);
+        phrase.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn jumble<'a>(clues: &[&str], words: &'a WordList) -> Jumble<'a> {
+        let clues = clues.iter().map(|c| c.to_string()).collect::<Vec<String>>();
+        Jumble::new(&clues, words).unwrap()
+    }
+
+    #[test]
+    fn test_parse_clue() {
+        assert_eq!(
+            "NAGLE:1,3".parse::<Clue>(),
+            Ok(Clue {
+                letters: "nagle".to_string(),
+                circles: vec![0, 2],
+            })
+        );
+        assert_eq!(
+            "nagle:6".parse::<Clue>(),
+            Err(Error::JumbleClue("nagle:6".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_circled_letters_make_final_answer() {
+        // angle and glean give `ag` and `ge`, heart, earth and hater give
+        // `t`, `h` or `r`, and only `agt` makes a word.
+        let words = WordList::from_words([
+            "angle", "glean", "heart", "earth", "hater", "tag", "hen", "the", "eat", "tea", "hate",
+        ]);
+        let mut jumble = jumble(&["nagle:1,3", "trahe:5"], &words);
+        jumble.find_solutions().unwrap();
+
+        let answers = jumble
+            .solutions()
+            .iter()
+            .flat_map(|s| s.answers.clone())
+            .collect::<Vec<String>>();
+        assert_eq!(answers, vec!["tag"]);
+        assert_eq!(jumble.solutions()[0].words, vec!["angle", "heart"]);
+        assert_eq!(jumble.solutions().len(), 6);
+    }
+
+    #[test]
+    fn test_answer_with_several_words() {
+        let words = WordList::from_words([
+            "angle", "glean", "heart", "earth", "hater", "tag", "hen", "the", "eat", "tea", "hate",
+        ]);
+        let mut jumble = jumble(&["tahe:1,2,3,4", "ate:1,2,3"], &words);
+        jumble
+            .set_answer(Some("___ ____".to_string()))
+            .unwrap()
+            .find_solutions()
+            .unwrap();
+
+        assert!(
+            jumble.solutions()[0]
+                .answers
+                .contains(&"eat hate".to_string())
+        );
+        assert_eq!(
+            jumble.set_answer(Some("__a".to_string())).err(),
+            Some(Error::JumbleAnswer("__a".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_unsolved_clue() {
+        let words = WordList::from_words([
+            "angle", "glean", "heart", "earth", "hater", "tag", "hen", "the", "eat", "tea", "hate",
+        ]);
+        let mut jumble = jumble(&["zzz"], &words);
+        assert_eq!(
+            jumble.find_solutions().err(),
+            Some(Error::JumbleUnsolved("zzz".to_string()))
+        );
+    }
+}
@@ -0,0 +1,184 @@
+use colorful::Colorful;
+
+use crate::{Distribution, Error, WordFilters, WordList};
+
+const TWIST_MINIMUM_LENGTH: usize = 3;
+
+#[derive(Debug)]
+pub struct TextTwist<'a> {
+    letters: Vec<char>,
+    words: &'a WordList,
+    solutions: Vec<String>,
+    distribution: Distribution,
+}
+
+impl<'a> TextTwist<'a> {
+    pub fn new(letters: &str, words: &'a WordList) -> Result<Self, Error> {
+        if !(6..=7).contains(&letters.chars().count())
+            || !letters.chars().all(|l| l.is_ascii_alphabetic())
+        {
+            return Err(Error::TwistLetters(letters.to_string()));
+        }
+
+        let letters = letters
+            .chars()
+            .map(|l| l.to_ascii_lowercase())
+            .collect::<Vec<char>>();
+
+        Ok(Self {
+            words,
+            letters,
+            solutions: Vec::new(),
+            distribution: Distribution::new(),
+        })
+    }
+
+    pub fn solutions(&self) -> &[String] {
+        &self.solutions
+    }
+
+    /// Find every word of three or more letters that can be made from the
+    /// letters, using each letter no more often than it appears.
+    #[tracing::instrument(skip(self))]
+    pub fn find_solutions(&mut self) -> Result<&mut Self, Error> {
+        let letters = self.letters.iter().collect::<String>();
+
+        let mut filtered = self
+            .words
+            .fitting_letters(&letters)
+            .iter()
+            .map(|w| w.to_lowercase())
+            .collect::<Vec<String>>()
+            .filter_to_minimum_length(TWIST_MINIMUM_LENGTH);
+        filtered.sort_by(|a, b| a.len().cmp(&b.len()).then_with(|| a.cmp(b)));
+        filtered.dedup();
+        tracing::debug!("{} words found", filtered.len());
+
+        for word in filtered.iter() {
+            self.count_solution(word.len());
+        }
+        self.solutions = filtered;
+
+        Ok(self)
+    }
+
+    pub fn count_solution(&mut self, word_length: usize) -> &mut Self {
+        self.distribution.count(word_length);
+        self
+    }
+
+    pub fn word_source_string(&self) -> String {
+        self.words.source_string()
+    }
+
+    /// Count of words by length and the words that use every letter, which
+    /// are needed to move on to the next round.
+    pub fn board_string(&self) -> String {
+        let mut s = String::new();
+        for d in self.distribution.lengths() {
+            s.push_str(&format!(
+                "  - {:3.0} solutions with {:2.0} letters\n",
+                d.1, d.0
+            ));
+        }
+
+        let full = self
+            .solutions
+            .iter()
+            .filter(|w| w.len() == self.letters.len())
+            .cloned()
+            .collect::<Vec<String>>();
+        s.push_str(&format!("\n  {} words in total\n", self.solutions.len()));
+        if full.is_empty() {
+            s.push_str("  No word uses every letter\n");
+        } else {
+            s.push_str(&format!("  Using every letter: {}\n", full.join(", ")));
+        }
+        s
+    }
+
+    pub fn solutions_title(&self) -> String {
+        let intro = "Text twist ";
+        let mut ul = String::new();
+        for _ in 0..(intro.len() + self.letters.len()) {
+            ul.push('‾');
+        }
+
+        let summary = format!(
+            "{}{}",
+            intro.yellow().bold(),
+            self.letters.iter().collect::<String>().blue().bold()
+        );
+        format!("{}\n{}", summary, ul.bold().yellow())
+    }
+
+    pub fn solutions_string(&self) -> String {
+        let mut s = String::new();
+        let mut word_length = 0;
+
+        for solution in self.solutions.iter() {
+            if solution.len() != word_length {
+                if word_length != 0 {
+                    s.push('\n');
+                }
+                word_length = solution.len();
+                s.push_str("  ");
+                s.push_str(
+                    &format!(
+                        "{} Solutions with {} letters.",
+                        self.distribution.get(word_length),
+                        word_length
+                    )
+                    .underlined()
+                    .yellow()
+                    .to_string(),
+                );
+                s.push_str("\n\n");
+            }
+            s.push_str(&format!("    {solution}\n"));
+        }
+        s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all_sub_anagrams() {
+        let words = WordList::from_words([
+            "tinsel", "listen", "silent", "inlets", "lens", "tin", "it", "tinsels",
+        ]);
+
+        let mut twist = TextTwist::new("LISTEN", &words).unwrap();
+        twist.find_solutions().unwrap();
+
+        assert_eq!(
+            twist.solutions(),
+            vec!["tin", "lens", "inlets", "listen", "silent", "tinsel"]
+        );
+        assert!(
+            twist
+                .board_string()
+                .contains("Using every letter: inlets, listen, silent, tinsel")
+        );
+    }
+
+    #[test]
+    fn test_twist_needs_six_or_seven_letters() {
+        assert_eq!(
+            TextTwist::new("abcde", &WordList::default()).err(),
+            Some(Error::TwistLetters("abcde".to_string()))
+        );
+        assert_eq!(
+            TextTwist::new("abcdé", &WordList::default()).err(),
+            Some(Error::TwistLetters("abcdé".to_string()))
+        );
+        assert_eq!(
+            TextTwist::new("abc-ef", &WordList::default()).err(),
+            Some(Error::TwistLetters("abc-ef".to_string()))
+        );
+        assert!(TextTwist::new("abcdefg", &WordList::default()).is_ok());
+    }
+}
@@ -0,0 +1,251 @@
+use colorful::Colorful;
+
+use crate::{Distribution, Error, WordFilters, WordList};
+
+const WHEEL_LETTERS: usize = 9;
+const WHEEL_MINIMUM_LENGTH: usize = 4;
+
+#[derive(Debug)]
+pub struct WordWheel<'a> {
+    letters: Vec<char>,
+    centre: char,
+    words: &'a WordList,
+    solutions: Vec<String>,
+    distribution: Distribution,
+}
+
+impl<'a> WordWheel<'a> {
+    /// The nine letters of the wheel. The first letter is the centre unless
+    /// another is chosen with `set_centre`.
+    pub fn new(letters: &str, words: &'a WordList) -> Result<Self, Error> {
+        if letters.chars().count() != WHEEL_LETTERS
+            || !letters.chars().all(|l| l.is_ascii_alphabetic())
+        {
+            return Err(Error::WheelLetters(letters.to_string()));
+        }
+
+        let letters = letters
+            .chars()
+            .map(|l| l.to_ascii_lowercase())
+            .collect::<Vec<char>>();
+
+        Ok(Self {
+            words,
+            centre: letters[0],
+            letters,
+            solutions: Vec::new(),
+            distribution: Distribution::new(),
+        })
+    }
+
+    pub fn set_centre(&mut self, value: Option<char>) -> Result<&mut Self, Error> {
+        if let Some(centre) = value {
+            let centre = centre.to_ascii_lowercase();
+            if !self.letters.contains(&centre) {
+                return Err(Error::WheelCentre(centre));
+            }
+            self.centre = centre;
+        }
+        Ok(self)
+    }
+
+    pub fn solutions(&self) -> &[String] {
+        &self.solutions
+    }
+
+    /// Find the words of four or more letters that use the centre letter and
+    /// each letter of the wheel no more than once.
+    #[tracing::instrument(skip(self))]
+    pub fn find_solutions(&mut self) -> Result<&mut Self, Error> {
+        let letters = self.letters.iter().collect::<String>();
+
+        let mut filtered = self
+            .words
+            .including_all_letters(&self.centre.to_string())
+            .fitting_letters(&letters)
+            .iter()
+            .map(|w| w.to_lowercase())
+            .collect::<Vec<String>>()
+            .filter_to_minimum_length(WHEEL_MINIMUM_LENGTH);
+        filtered.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        filtered.dedup();
+        tracing::debug!("{} words found", filtered.len());
+
+        for word in filtered.iter() {
+            self.count_solution(word.len());
+        }
+        self.solutions = filtered;
+
+        Ok(self)
+    }
+
+    pub fn count_solution(&mut self, word_length: usize) -> &mut Self {
+        self.distribution.count(word_length);
+        self
+    }
+
+    pub fn word_source_string(&self) -> String {
+        self.words.source_string()
+    }
+
+    /// The wheel with the centre letter in the middle of a three by three
+    /// block, followed by the count of words and nine letter words.
+    pub fn board_string(&self) -> String {
+        let mut outer = self.letters.clone();
+        if let Some(i) = outer.iter().position(|c| *c == self.centre) {
+            outer.remove(i);
+        }
+        let cells = [
+            outer[0],
+            outer[1],
+            outer[2],
+            outer[7],
+            self.centre,
+            outer[3],
+            outer[6],
+            outer[5],
+            outer[4],
+        ];
+
+        let mut s = String::new();
+        for row in cells.chunks(3) {
+            let row = row
+                .iter()
+                .map(|c| c.to_ascii_uppercase().to_string())
+                .collect::<Vec<String>>()
+                .join(" ");
+            s.push_str(&format!("    {row}\n"));
+        }
+
+        let nine = self
+            .solutions
+            .iter()
+            .filter(|w| w.len() == WHEEL_LETTERS)
+            .cloned()
+            .collect::<Vec<String>>();
+        s.push_str(&format!(
+            "\n  {} words using the centre letter {}\n",
+            self.solutions.len(),
+            self.centre.to_ascii_uppercase()
+        ));
+        if nine.is_empty() {
+            s.push_str("  No nine letter word\n");
+        } else {
+            s.push_str(&format!("  Nine letter word: {}\n", nine.join(", ")));
+        }
+        s
+    }
+
+    pub fn solutions_title(&self) -> String {
+        let intro = "Word wheel ";
+        let mut ul = String::new();
+        for _ in 0..(intro.len() + self.letters.len()) {
+            ul.push('‾');
+        }
+
+        let summary = format!(
+            "{}{}",
+            intro.yellow().bold(),
+            self.letters.iter().collect::<String>().blue().bold()
+        );
+        format!("{}\n{}", summary, ul.bold().yellow())
+    }
+
+    pub fn solutions_string(&self) -> String {
+        let mut s = String::new();
+        let mut word_length = 0;
+
+        for solution in self.solutions.iter() {
+            if solution.len() != word_length {
+                if word_length != 0 {
+                    s.push('\n');
+                }
+                word_length = solution.len();
+                s.push_str("  ");
+                s.push_str(
+                    &format!(
+                        "{} Solutions with {} letters.",
+                        self.distribution.get(word_length),
+                        word_length
+                    )
+                    .underlined()
+                    .yellow()
+                    .to_string(),
+                );
+                s.push_str("\n\n");
+            }
+            s.push_str(&format!("    {solution}\n"));
+        }
+        s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_words_use_centre_letter_once() {
+        let words = WordList::from_words([
+            "nominates",
+            "mansion",
+            "stamen",
+            "means",
+            "tames",
+            "mint",
+            "seat",
+            "mat",
+            "moose",
+        ]);
+        let mut wheel = WordWheel::new("mnoinates", &words).unwrap();
+        wheel.find_solutions().unwrap();
+
+        assert_eq!(
+            wheel.solutions(),
+            vec!["nominates", "mansion", "stamen", "means", "tames", "mint"]
+        );
+        assert!(wheel.board_string().contains("Nine letter word: nominates"));
+    }
+
+    #[test]
+    fn test_choose_centre_letter() {
+        let words = WordList::from_words([
+            "nominates",
+            "mansion",
+            "stamen",
+            "means",
+            "tames",
+            "mint",
+            "seat",
+            "mat",
+            "moose",
+        ]);
+        let mut wheel = WordWheel::new("mnoinates", &words).unwrap();
+        wheel
+            .set_centre(Some('S'))
+            .unwrap()
+            .find_solutions()
+            .unwrap();
+        assert_eq!(
+            wheel.solutions(),
+            vec!["nominates", "mansion", "stamen", "means", "tames", "seat"]
+        );
+
+        assert_eq!(
+            wheel.set_centre(Some('z')).err(),
+            Some(Error::WheelCentre('z'))
+        );
+        assert_eq!(
+            WordWheel::new("abc", &words).err(),
+            Some(Error::WheelLetters("abc".to_string()))
+        );
+        assert_eq!(
+            WordWheel::new("nominat1s", &words).err(),
+            Some(Error::WheelLetters("nominat1s".to_string()))
+        );
+        assert_eq!(
+            WordWheel::new("nominaté", &words).err(),
+            Some(Error::WheelLetters("nominaté".to_string()))
+        );
+    }
+}
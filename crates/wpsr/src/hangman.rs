@@ -0,0 +1,221 @@
+use colorful::Colorful;
+
+use crate::{Error, WordFilters, WordList};
+
+const ALPHABET: &str = "abcdefghijklmnopqrstuvwxyz";
+
+#[derive(Debug)]
+pub struct Hangman<'a> {
+    pattern: String,
+    wrong: String,
+    words: &'a WordList,
+    max: usize,
+    candidates: Vec<String>,
+    recommendations: Vec<(char, usize)>,
+}
+
+impl<'a> Hangman<'a> {
+    /// Start from the revealed pattern, with `_` for each letter still to be
+    /// guessed, for example `_a__e_`.
+    pub fn new(pattern: &str, words: &'a WordList) -> Result<Self, Error> {
+        let pattern = pattern.to_ascii_lowercase();
+        if pattern.is_empty() || !pattern.chars().all(|c| c == '_' || c.is_ascii_lowercase()) {
+            return Err(Error::HangmanPattern(pattern));
+        }
+
+        Ok(Self {
+            words,
+            pattern,
+            max: 20,
+            wrong: String::new(),
+            candidates: Vec::new(),
+            recommendations: Vec::new(),
+        })
+    }
+
+    /// Letters already guessed that are not in the word.
+    pub fn set_wrong(&mut self, value: Option<String>) -> Result<&mut Self, Error> {
+        let wrong = value.unwrap_or_default().to_ascii_lowercase();
+        if let Some(letter) = wrong
+            .chars()
+            .find(|c| !c.is_ascii_lowercase() || self.pattern.contains(*c))
+        {
+            return Err(Error::HangmanGuess(letter));
+        }
+        self.wrong = wrong;
+        Ok(self)
+    }
+
+    pub fn set_max_solutions(&mut self, value: usize) -> &mut Self {
+        self.max = value;
+        self
+    }
+
+    pub fn candidates(&self) -> &[String] {
+        &self.candidates
+    }
+
+    pub fn recommendations(&self) -> &[(char, usize)] {
+        &self.recommendations
+    }
+
+    /// Find the words that fit the pattern and rank the unguessed letters by
+    /// the number of those words that contain them.
+    #[tracing::instrument(skip(self))]
+    pub fn find_solutions(&mut self) -> Result<&mut Self, Error> {
+        let revealed = self
+            .pattern
+            .chars()
+            .filter(|c| *c != '_')
+            .collect::<String>();
+
+        let mut candidates = self
+            .words
+            .excluding_letters(&self.wrong)
+            .iter()
+            .map(|w| w.to_lowercase())
+            .collect::<Vec<String>>()
+            .filter_matches_pattern(&self.pattern);
+        // A revealed letter shows at every place it occurs, so the blanks
+        // cannot hold any of the revealed letters.
+        candidates.retain(|word| {
+            word.chars()
+                .zip(self.pattern.chars())
+                .all(|(letter, p)| p != '_' || !revealed.contains(letter))
+        });
+        candidates.sort();
+        candidates.dedup();
+        tracing::debug!("{} candidates", candidates.len());
+
+        if candidates.is_empty() {
+            return Err(Error::NoWordFound);
+        }
+
+        let mut recommendations = ALPHABET
+            .chars()
+            .filter(|c| !revealed.contains(*c) && !self.wrong.contains(*c))
+            .map(|c| (c, candidates.iter().filter(|w| w.contains(c)).count()))
+            .filter(|(_, count)| *count > 0)
+            .collect::<Vec<(char, usize)>>();
+        recommendations.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+        self.candidates = candidates;
+        self.recommendations = recommendations;
+
+        Ok(self)
+    }
+
+    pub fn word_source_string(&self) -> String {
+        self.words.source_string()
+    }
+
+    pub fn solutions_title(&self) -> String {
+        let intro = "Hangman candidates for ";
+        let mut ul = String::new();
+        for _ in 0..(intro.len() + self.pattern.len()) {
+            ul.push('‾');
+        }
+
+        let summary = format!(
+            "{}{}",
+            intro.yellow().bold(),
+            self.pattern.clone().blue().bold()
+        );
+        format!("{}\n{}", summary, ul.bold().yellow())
+    }
+
+    pub fn recommendations_string(&self) -> String {
+        let mut s = String::new();
+        let total = self.candidates.len();
+
+        s.push_str("  ");
+        s.push_str(
+            &format!("{total} candidates remain.")
+                .underlined()
+                .yellow()
+                .to_string(),
+        );
+        s.push_str("\n\n");
+
+        for (letter, count) in self.recommendations.iter() {
+            s.push_str(&format!(
+                "  - {letter} is in {count:3.0} candidates ({:3.0}%)\n",
+                *count as f64 * 100.0 / total as f64
+            ));
+        }
+        s
+    }
+
+    pub fn solutions_string(&self) -> String {
+        let mut s = String::new();
+
+        for word in self.candidates.iter().take(self.max) {
+            s.push_str(&format!("    {word}\n"));
+        }
+        if self.candidates.len() > self.max {
+            s.push_str(&format!(
+                "    ... and {} more\n",
+                self.candidates.len() - self.max
+            ));
+        }
+        s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_candidates_exclude_wrong_and_revealed_letters() {
+        let words = WordList::from_words([
+            "baker", "maker", "taker", "wafer", "water", "later", "cater", "eater", "paper",
+        ]);
+        let mut hangman = Hangman::new("_a_er", &words).unwrap();
+        hangman
+            .set_wrong(Some("tbp".to_string()))
+            .unwrap()
+            .find_solutions()
+            .unwrap();
+
+        // eater has an `e` in a blank, which would have been revealed.
+        assert_eq!(hangman.candidates(), vec!["maker", "wafer"]);
+    }
+
+    #[test]
+    fn test_recommend_by_candidates_containing_letter() {
+        let words = WordList::from_words([
+            "baker", "maker", "taker", "wafer", "water", "later", "cater", "eater", "paper",
+        ]);
+        let mut hangman = Hangman::new("_a_er", &words).unwrap();
+        hangman.find_solutions().unwrap();
+
+        assert_eq!(
+            hangman.candidates(),
+            vec![
+                "baker", "cater", "later", "maker", "paper", "taker", "wafer", "water"
+            ]
+        );
+        assert_eq!(hangman.recommendations()[0], ('t', 4));
+        assert_eq!(hangman.recommendations()[1], ('k', 3));
+        assert!(hangman.recommendations().iter().all(|(c, _)| *c != 'a'));
+    }
+
+    #[test]
+    fn test_invalid_pattern_and_guesses() {
+        let words = WordList::from_words([
+            "baker", "maker", "taker", "wafer", "water", "later", "cater", "eater", "paper",
+        ]);
+        assert_eq!(
+            Hangman::new("_a-", &words).err(),
+            Some(Error::HangmanPattern("_a-".to_string()))
+        );
+        assert_eq!(
+            Hangman::new("_a_er", &words)
+                .unwrap()
+                .set_wrong(Some("ta".to_string()))
+                .err(),
+            Some(Error::HangmanGuess('a'))
+        );
+    }
+}
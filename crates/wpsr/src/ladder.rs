@@ -0,0 +1,408 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use colorful::Colorful;
+
+use crate::{Error, WordList};
+
+const ALPHABET: &str = "abcdefghijklmnopqrstuvwxyz";
+
+#[derive(Debug)]
+pub struct Ladder<'a> {
+    words: &'a WordList,
+    resize: bool,
+    max: usize,
+    title: String,
+    ladders: Vec<Vec<String>>,
+}
+
+impl<'a> Ladder<'a> {
+    pub fn new(words: &'a WordList) -> Self {
+        Self {
+            words,
+            max: 10,
+            resize: false,
+            title: String::new(),
+            ladders: Vec::new(),
+        }
+    }
+
+    /// Allow steps that add or remove a letter as well as those that change one.
+    pub fn set_resize(&mut self, value: bool) -> &mut Self {
+        self.resize = value;
+        self
+    }
+
+    pub fn set_max_solutions(&mut self, value: usize) -> &mut Self {
+        self.max = value;
+        self
+    }
+
+    pub fn ladders(&self) -> &[Vec<String>] {
+        &self.ladders
+    }
+
+    /// Find every shortest ladder from the start word to the end word using a
+    /// breadth first search over the word list.
+    #[tracing::instrument(skip(self))]
+    pub fn find_ladders(&mut self, start: &str, end: &str) -> Result<&mut Self, Error> {
+        let start = start.to_lowercase();
+        let end = end.to_lowercase();
+        let length = start.chars().count();
+        if !self.resize && length != end.chars().count() {
+            return Err(Error::LadderLength(start, end));
+        }
+        self.title = format!("{start} to {end}");
+
+        let dictionary = self
+            .words
+            .iter()
+            .filter(|w| self.resize || w.chars().count() == length)
+            .map(|w| w.to_lowercase())
+            .collect::<HashSet<String>>();
+        for word in [&start, &end] {
+            if !dictionary.contains(word) {
+                return Err(Error::LadderWord(word.to_string()));
+            }
+        }
+
+        // Breadth first search, layer by layer, remembering every parent that
+        // reaches a word at the shortest distance.
+        let mut parents: HashMap<String, Vec<String>> = HashMap::new();
+        let mut visited = HashSet::from([start.clone()]);
+        let mut layer = vec![start.clone()];
+        while !layer.is_empty() && !visited.contains(&end) {
+            let mut next_layer = HashMap::new();
+            for word in layer.iter() {
+                for next in neighbours(word, &dictionary, self.resize) {
+                    if !visited.contains(&next) {
+                        next_layer
+                            .entry(next.clone())
+                            .or_insert_with(Vec::new)
+                            .push(word.clone());
+                    }
+                }
+            }
+            for (word, word_parents) in next_layer.iter_mut() {
+                word_parents.sort();
+                visited.insert(word.clone());
+                parents.insert(word.clone(), word_parents.clone());
+            }
+            layer = next_layer.into_keys().collect();
+            layer.sort();
+            tracing::debug!("Next layer has {} words", layer.len());
+        }
+
+        if !visited.contains(&end) {
+            return Err(Error::NoLadderFound(start, end));
+        }
+
+        let mut ladders = Vec::new();
+        let mut path = vec![end.clone()];
+        collect_ladders(&end, &start, &parents, &mut path, &mut ladders, self.max);
+        ladders.sort();
+        self.ladders = ladders;
+
+        Ok(self)
+    }
+
+    /// Find the pairs of words of the given length that are furthest apart
+    /// while still being connected by a ladder.
+    #[tracing::instrument(skip(self))]
+    pub fn find_diameter(&mut self, length: usize) -> Result<&mut Self, Error> {
+        let mut words = self
+            .words
+            .iter()
+            .filter(|w| w.chars().count() == length)
+            .map(|w| w.to_lowercase())
+            .collect::<Vec<String>>();
+        words.sort();
+        words.dedup();
+        self.title = format!("the longest ladders between {length} letter words");
+
+        let adjacency = adjacency(&words);
+
+        // Ladders are traced while each search's parents are still to hand,
+        // keeping only the first few pairs at the longest distance so far.
+        let mut diameter = 0;
+        let mut pairs = 0;
+        let mut ladders = Vec::new();
+        for source in 0..words.len() {
+            let (distances, parents) = breadth_first(source, &adjacency);
+            for (target, distance) in distances.iter().enumerate() {
+                let Some(distance) = distance else {
+                    continue;
+                };
+                if target <= source || *distance < diameter {
+                    continue;
+                }
+                if *distance > diameter {
+                    diameter = *distance;
+                    pairs = 0;
+                    ladders.clear();
+                }
+                pairs += 1;
+                if ladders.len() < self.max {
+                    ladders.push(trace_ladder(target, &parents, &words));
+                }
+            }
+        }
+        tracing::info!("Diameter is {diameter} with {pairs} pairs");
+
+        if diameter == 0 {
+            return Err(Error::NoWordFound);
+        }
+
+        self.ladders = ladders;
+
+        Ok(self)
+    }
+
+    pub fn word_source_string(&self) -> String {
+        self.words.source_string()
+    }
+
+    pub fn solutions_title(&self) -> String {
+        let intro = "Word ladders for ";
+        let mut ul = String::new();
+        for _ in 0..(intro.len() + self.title.len()) {
+            ul.push('‾');
+        }
+
+        let summary = format!(
+            "{}{}",
+            intro.yellow().bold(),
+            self.title.clone().blue().bold()
+        );
+        format!("{}\n{}", summary, ul.bold().yellow())
+    }
+
+    pub fn solutions_string(&self) -> String {
+        let mut s = String::new();
+        let steps = self.ladders.first().map_or(0, |l| l.len() - 1);
+
+        s.push_str("  ");
+        s.push_str(
+            &format!("{} ladders with {} steps.", self.ladders.len(), steps)
+                .underlined()
+                .yellow()
+                .to_string(),
+        );
+        s.push_str("\n\n");
+
+        for ladder in self.ladders.iter() {
+            s.push_str(&format!("    {}\n", ladder.join(" -> ")));
+        }
+        s
+    }
+}
+
+/// Words in the dictionary one step away from the word.
+fn neighbours(word: &str, dictionary: &HashSet<String>, resize: bool) -> Vec<String> {
+    let chars = word.chars().collect::<Vec<char>>();
+    let mut found = HashSet::new();
+
+    for i in 0..chars.len() {
+        for letter in ALPHABET.chars() {
+            if letter != chars[i] {
+                let mut candidate = chars.clone();
+                candidate[i] = letter;
+                found.insert(candidate.iter().collect::<String>());
+            }
+        }
+    }
+
+    if resize {
+        for i in 0..=chars.len() {
+            for letter in ALPHABET.chars() {
+                let mut candidate = chars.clone();
+                candidate.insert(i, letter);
+                found.insert(candidate.iter().collect::<String>());
+            }
+        }
+        for i in 0..chars.len() {
+            let mut candidate = chars.clone();
+            candidate.remove(i);
+            found.insert(candidate.iter().collect::<String>());
+        }
+    }
+
+    let mut found = found
+        .into_iter()
+        .filter(|w| w != word && dictionary.contains(w))
+        .collect::<Vec<String>>();
+    found.sort();
+    found
+}
+
+fn collect_ladders(
+    word: &str,
+    start: &str,
+    parents: &HashMap<String, Vec<String>>,
+    path: &mut Vec<String>,
+    ladders: &mut Vec<Vec<String>>,
+    max: usize,
+) {
+    if ladders.len() >= max {
+        return;
+    }
+    if word == start {
+        ladders.push(path.iter().rev().cloned().collect());
+        return;
+    }
+    for parent in parents.get(word).into_iter().flatten() {
+        path.push(parent.clone());
+        collect_ladders(parent, start, parents, path, ladders, max);
+        path.pop();
+    }
+}
+
+/// Link words that differ by one letter by grouping them under patterns with
+/// one letter replaced by a wildcard.
+fn adjacency(words: &[String]) -> Vec<Vec<usize>> {
+    let mut buckets: HashMap<String, Vec<usize>> = HashMap::new();
+    for (index, word) in words.iter().enumerate() {
+        let letters = word.chars().collect::<Vec<char>>();
+        for i in 0..letters.len() {
+            let mut pattern = letters.clone();
+            pattern[i] = '_';
+            buckets
+                .entry(pattern.into_iter().collect())
+                .or_default()
+                .push(index);
+        }
+    }
+
+    let mut adjacency = vec![Vec::new(); words.len()];
+    for bucket in buckets.values() {
+        for a in bucket {
+            for b in bucket {
+                if a != b {
+                    adjacency[*a].push(*b);
+                }
+            }
+        }
+    }
+    adjacency
+}
+
+/// Follow the parents from the target back to the source of the search.
+fn trace_ladder(target: usize, parents: &[Option<usize>], words: &[String]) -> Vec<String> {
+    let mut ladder = vec![words[target].clone()];
+    let mut current = target;
+    while let Some(parent) = parents[current] {
+        ladder.push(words[parent].clone());
+        current = parent;
+    }
+    ladder.reverse();
+    ladder
+}
+
+fn breadth_first(
+    source: usize,
+    adjacency: &[Vec<usize>],
+) -> (Vec<Option<usize>>, Vec<Option<usize>>) {
+    let mut distances = vec![None; adjacency.len()];
+    let mut parents = vec![None; adjacency.len()];
+    let mut queue = VecDeque::from([source]);
+    distances[source] = Some(0);
+
+    while let Some(current) = queue.pop_front() {
+        let distance = distances[current].unwrap_or(0);
+        for next in adjacency[current].iter() {
+            if distances[*next].is_none() {
+                distances[*next] = Some(distance + 1);
+                parents[*next] = Some(current);
+                queue.push_back(*next);
+            }
+        }
+    }
+    (distances, parents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all_shortest_ladders() {
+        let words = WordList::from_words([
+            "cold", "cord", "card", "ward", "warm", "word", "worm", "wore", "core", "corm",
+        ]);
+        let mut ladder = Ladder::new(&words);
+        ladder.find_ladders("cold", "warm").unwrap();
+
+        assert_eq!(
+            ladder.ladders(),
+            vec![
+                vec!["cold", "cord", "card", "ward", "warm"],
+                vec!["cold", "cord", "corm", "worm", "warm"],
+                vec!["cold", "cord", "word", "ward", "warm"],
+                vec!["cold", "cord", "word", "worm", "warm"],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ladder_with_resize() {
+        let words = WordList::from_words(["cat", "cart", "card", "cad"]);
+        let mut ladder = Ladder::new(&words);
+        assert_eq!(
+            ladder.find_ladders("cat", "card").err(),
+            Some(Error::LadderLength("cat".to_string(), "card".to_string()))
+        );
+
+        ladder.set_resize(true).find_ladders("cat", "card").unwrap();
+        assert_eq!(
+            ladder.ladders(),
+            vec![vec!["cat", "cad", "card"], vec!["cat", "cart", "card"]]
+        );
+    }
+
+    #[test]
+    fn test_no_ladder() {
+        let words = WordList::from_words(["cold", "cord", "warm"]);
+        let mut ladder = Ladder::new(&words);
+        assert_eq!(
+            ladder.find_ladders("cold", "warm").err(),
+            Some(Error::NoLadderFound("cold".to_string(), "warm".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_words_not_in_list() {
+        let words = WordList::from_words(["cold", "cord", "card"]);
+        let mut ladder = Ladder::new(&words);
+        assert_eq!(
+            ladder.find_ladders("cold", "ward").err(),
+            Some(Error::LadderWord("ward".to_string()))
+        );
+        assert_eq!(
+            ladder.find_ladders("bold", "card").err(),
+            Some(Error::LadderWord("bold".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_words_with_accents() {
+        let words = WordList::from_words(["café", "cafe", "safe", "sage", "éclat"]);
+        let mut ladder = Ladder::new(&words);
+        ladder.find_ladders("cafe", "sage").unwrap();
+        assert_eq!(ladder.ladders(), vec![vec!["cafe", "safe", "sage"]]);
+
+        ladder.find_diameter(4).unwrap();
+        assert_eq!(ladder.ladders(), vec![vec!["café", "cafe", "safe", "sage"]]);
+    }
+
+    #[test]
+    fn test_diameter() {
+        let words = WordList::from_words(["cat", "cot", "dot", "dog", "cog", "zzz"]);
+        let mut ladder = Ladder::new(&words);
+        ladder.find_diameter(3).unwrap();
+
+        assert_eq!(ladder.ladders().len(), 1);
+        let longest = &ladder.ladders()[0];
+        assert_eq!(longest.len(), 4);
+        assert_eq!(longest.first().unwrap(), "cat");
+        assert_eq!(longest.last().unwrap(), "dog");
+    }
+}
@@ -1,20 +1,57 @@
 mod anagram;
+mod bee;
+mod boggle;
 mod boxed;
 mod cli;
+mod codeword;
+mod countdown;
+mod cryptogram;
+mod distribution;
 mod error;
+mod fill;
+mod games;
+mod hangman;
+mod ladder;
+mod scrabble;
+mod trie;
+mod waffle;
 mod word_filters;
+mod word_list;
+mod wordle;
 mod words;
+mod wordsearch;
 
 pub use anagram::Anagram;
-pub use boxed::{LettersBoxed, Shape, Shuffle, Solution};
+pub use bee::{Bee, RANKS, bee_score};
+pub use boggle::{Board, BoardGenerator, Boggle, DiceSet, word_score};
+pub use boxed::{Archive, ArchiveEntry, LettersBoxed, Shape, Shuffle, Solution, today};
 pub use cli::{Cli, Commands};
+pub use codeword::{Codeword, CodewordGrid};
+pub use countdown::{LettersRound, NumbersRound, Operation, Step};
+pub use cryptogram::{Cryptogram, Key, word_pattern};
+pub use distribution::Distribution;
 pub use error::Error;
+pub use fill::{Fill, Slot, Template};
+pub use games::{Clue, Jumble, JumbleAnswer, TextTwist, WordWheel};
+pub use hangman::Hangman;
+pub use ladder::Ladder;
+pub use scrabble::{Play, Premium, Scrabble, Square, letter_value};
+pub use trie::Trie;
+pub use waffle::{Colour, Swap, Waffle};
 pub use word_filters::WordFilters;
+pub use word_list::{
+    BinaryWordList, LETTERS, LetterCounts, ListCheck, ListFormat, ListStats, MappedWordList, OTHER,
+    WordKind, WordList, encode_binary, letter_counts, letter_mask, signature,
+};
+pub use wordle::{Mark, Wordle, WordleBoard, feedback};
 pub use words::Words;
+pub use wordsearch::{Direction, Directions, Grid, Placement, PuzzleGenerator, WordSearch};
 
 pub const DEFAULT_SOURCE_DIR: &str = "/usr/lib/wpsr/words";
 pub const DEFAULT_WORDS_SOURCE_FILE: &str = "default.txt";
 pub const DEFAULT_BOXED_SOURCE_FILE: &str = "default.slb";
+pub const DEFAULT_BEE_DIR: &str = ".wpsr/bee";
+pub const DEFAULT_BOXED_ARCHIVE_DIR: &str = ".wpsr/boxed";
 pub const DEFAULT_MINIMUM_WORD_LENGTH: usize = 3;
 pub const DEFAULT_LINE_LENGTH: usize = 3010;
 pub const DEFAULT_CONFIG_FILE_BASENAME: &str = "wpsr";
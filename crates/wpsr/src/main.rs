@@ -3,8 +3,9 @@ use std::{collections::HashMap, path::PathBuf, str::FromStr};
 use clap::Parser;
 use config::{Config, File};
 use lib_wpsr::{
-    Cli, Commands, DEFAULT_BOXED_SOURCE_FILE, DEFAULT_CONFIG_FILE_BASENAME, DEFAULT_LINE_LENGTH,
-    DEFAULT_MINIMUM_WORD_LENGTH, DEFAULT_SOURCE_DIR, DEFAULT_WORDS_SOURCE_FILE,
+    Cli, Commands, DEFAULT_BEE_DIR, DEFAULT_BOXED_ARCHIVE_DIR, DEFAULT_BOXED_SOURCE_FILE,
+    DEFAULT_CONFIG_FILE_BASENAME, DEFAULT_LINE_LENGTH, DEFAULT_MINIMUM_WORD_LENGTH,
+    DEFAULT_SOURCE_DIR, DEFAULT_WORDS_SOURCE_FILE,
 };
 use tracing_subscriber::EnvFilter;
 
@@ -24,9 +25,25 @@ fn main() {
             let res = match args.cmd {
                 Commands::Alpha(alpha) => alpha.run(settings),
                 Commands::List(list) => list.run(settings),
+                Commands::Lists(lists) => lists.run(settings),
                 Commands::Anagram(anagram) => anagram.run(settings),
+                Commands::Bee(bee) => bee.run(settings),
+                Commands::Boggle(boggle) => boggle.run(settings),
                 Commands::Boxed(boxed) => boxed.run(settings),
+                Commands::Codeword(codeword) => codeword.run(settings),
+                Commands::Countdown(countdown) => countdown.run(settings),
+                Commands::Cryptogram(cryptogram) => cryptogram.run(settings),
+                Commands::Fill(fill) => fill.run(settings),
+                Commands::Hangman(hangman) => hangman.run(settings),
+                Commands::Jumble(jumble) => jumble.run(settings),
+                Commands::Ladder(ladder) => ladder.run(settings),
+                Commands::Scrabble(scrabble) => scrabble.run(settings),
+                Commands::Twist(twist) => twist.run(settings),
+                Commands::Waffle(waffle) => waffle.run(settings),
+                Commands::Wheel(wheel) => wheel.run(settings),
+                Commands::Wordle(wordle) => wordle.run(settings),
                 Commands::Words(words) => words.run(settings),
+                Commands::Wordsearch(wordsearch) => wordsearch.run(settings),
             };
             match res {
                 Ok(_) => {
@@ -82,11 +99,15 @@ pub fn get_settings(base_name: &str) -> Result<Config, config::ConfigError> {
             .set_default("source_dir", DEFAULT_SOURCE_DIR)?
             .set_default("source_words_file", DEFAULT_WORDS_SOURCE_FILE)?
             .set_default("source_boxed_file", DEFAULT_BOXED_SOURCE_FILE)?
+            .set_default("bee_dir", DEFAULT_BEE_DIR)?
+            .set_default("boxed_archive_dir", DEFAULT_BOXED_ARCHIVE_DIR)?
             .set_default(
                 "minimum_word_length",
                 DEFAULT_MINIMUM_WORD_LENGTH.to_string(),
             )?
             .set_default("line_length", DEFAULT_LINE_LENGTH.to_string())?
+            .set_default("allowlist", "")?
+            .set_default("blocklist", "")?
             .add_source(File::with_name(base_name))
             .build()
     } else {
@@ -95,11 +116,15 @@ pub fn get_settings(base_name: &str) -> Result<Config, config::ConfigError> {
             .set_default("source_dir", DEFAULT_SOURCE_DIR)?
             .set_default("source_words_file", DEFAULT_WORDS_SOURCE_FILE)?
             .set_default("source_boxed_file", DEFAULT_BOXED_SOURCE_FILE)?
+            .set_default("bee_dir", DEFAULT_BEE_DIR)?
+            .set_default("boxed_archive_dir", DEFAULT_BOXED_ARCHIVE_DIR)?
             .set_default(
                 "minimum_word_length",
                 DEFAULT_MINIMUM_WORD_LENGTH.to_string(),
             )?
             .set_default("line_length", DEFAULT_LINE_LENGTH.to_string())?
+            .set_default("allowlist", "")?
+            .set_default("blocklist", "")?
             .build()
     }
 }
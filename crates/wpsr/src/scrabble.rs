@@ -0,0 +1,402 @@
+use std::{collections::HashMap, fmt::Display};
+
+use colorful::Colorful;
+
+use crate::{Error, WordList};
+
+const RACK_SIZE: usize = 7;
+const BINGO_BONUS: u32 = 50;
+const BLANK: char = '?';
+
+/// Standard English tile values.
+pub fn letter_value(letter: char) -> u32 {
+    match letter {
+        'a' | 'e' | 'i' | 'l' | 'n' | 'o' | 'r' | 's' | 't' | 'u' => 1,
+        'd' | 'g' => 2,
+        'b' | 'c' | 'm' | 'p' => 3,
+        'f' | 'h' | 'v' | 'w' | 'y' => 4,
+        'k' => 5,
+        'j' | 'x' => 8,
+        'q' | 'z' => 10,
+        _ => 0,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Premium {
+    None,
+    DoubleLetter,
+    TripleLetter,
+    DoubleWord,
+    TripleWord,
+}
+
+impl Premium {
+    fn letter_multiplier(&self) -> u32 {
+        match self {
+            Premium::DoubleLetter => 2,
+            Premium::TripleLetter => 3,
+            _ => 1,
+        }
+    }
+
+    fn word_multiplier(&self) -> u32 {
+        match self {
+            Premium::DoubleWord => 2,
+            Premium::TripleWord => 3,
+            _ => 1,
+        }
+    }
+}
+
+/// A square on the board line, either empty (possibly with a premium) or
+/// holding a tile that has already been played.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Square {
+    Empty(Premium),
+    Tile(char),
+}
+
+impl Square {
+    /// Parse a board line such as `..a.e..`.
+    ///
+    /// `.` is an empty square, `2` and `3` are double and triple letter
+    /// squares, `D` and `T` are double and triple word squares and lower case
+    /// letters are tiles already on the board.
+    pub fn parse_line(line: &str) -> Result<Vec<Square>, Error> {
+        line.chars()
+            .map(|c| match c {
+                '.' => Ok(Square::Empty(Premium::None)),
+                '2' => Ok(Square::Empty(Premium::DoubleLetter)),
+                '3' => Ok(Square::Empty(Premium::TripleLetter)),
+                'D' => Ok(Square::Empty(Premium::DoubleWord)),
+                'T' => Ok(Square::Empty(Premium::TripleWord)),
+                'a'..='z' => Ok(Square::Tile(c)),
+                _ => Err(Error::ScrabbleBoard(c)),
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Play {
+    pub word: String,
+    pub score: u32,
+    /// Zero based square the word starts on when placed on a board line.
+    pub position: Option<usize>,
+}
+
+impl Display for Play {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.position {
+            Some(position) => write!(
+                f,
+                "{:3} {} at square {}",
+                self.score,
+                self.word,
+                position + 1
+            ),
+            None => write!(f, "{:3} {}", self.score, self.word),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Scrabble<'a> {
+    rack: Vec<char>,
+    words: &'a WordList,
+    board: Option<Vec<Square>>,
+    max: usize,
+    solutions: Vec<Play>,
+}
+
+impl<'a> Scrabble<'a> {
+    pub fn new(rack: &str, words: &'a WordList) -> Result<Self, Error> {
+        if rack.is_empty() || rack.len() > RACK_SIZE {
+            return Err(Error::ScrabbleRack(rack.len()));
+        }
+
+        let rack = rack
+            .chars()
+            .map(|l| l.to_ascii_lowercase())
+            .collect::<Vec<char>>();
+
+        Ok(Self {
+            words,
+            rack,
+            max: 50,
+            board: None,
+            solutions: Vec::new(),
+        })
+    }
+
+    pub fn set_max_solutions(&mut self, value: usize) -> &mut Self {
+        self.max = value;
+        self
+    }
+
+    pub fn set_board(&mut self, value: Option<String>) -> Result<&mut Self, Error> {
+        self.board = match value {
+            Some(line) => Some(Square::parse_line(&line)?),
+            None => None,
+        };
+        Ok(self)
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn find_solutions(&mut self) -> Result<&mut Self, Error> {
+        let rack = self.rack.iter().collect::<String>();
+
+        let mut plays = match &self.board {
+            None => self
+                .words
+                .fitting_letters(&rack)
+                .iter()
+                .map(|word| Play {
+                    score: rack_score(word, &self.rack),
+                    word: word.to_string(),
+                    position: None,
+                })
+                .collect::<Vec<Play>>(),
+            Some(board) => {
+                let board_letters = board
+                    .iter()
+                    .filter_map(|square| match square {
+                        Square::Tile(c) => Some(*c),
+                        Square::Empty(_) => None,
+                    })
+                    .collect::<String>();
+                self.words
+                    .fitting_letters(&format!("{rack}{board_letters}"))
+                    .iter()
+                    .filter_map(|word| best_placement(word, &self.rack, board))
+                    .collect::<Vec<Play>>()
+            }
+        };
+        tracing::debug!("{} plays found", plays.len());
+
+        plays.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.word.cmp(&b.word)));
+        plays.dedup_by(|a, b| a.word == b.word);
+        plays.truncate(self.max);
+
+        self.solutions = plays;
+
+        Ok(self)
+    }
+
+    pub fn solutions(&self) -> &[Play] {
+        &self.solutions
+    }
+
+    pub fn word_source_string(&self) -> String {
+        self.words.source_string()
+    }
+
+    pub fn solutions_title(&self) -> String {
+        let intro = "Plays for the rack ";
+        let mut ul = String::new();
+        for _ in 0..(intro.len() + self.rack.len()) {
+            ul.push('‾');
+        }
+
+        let summary = format!(
+            "{}{}",
+            intro.yellow().bold(),
+            self.rack.iter().collect::<String>().blue().bold()
+        );
+        format!("{}\n{}", summary, ul.bold().yellow())
+    }
+
+    pub fn solutions_string(&self) -> String {
+        let mut s = String::new();
+
+        s.push_str("  ");
+        s.push_str(
+            &format!("{} highest scoring plays.", self.solutions.len())
+                .underlined()
+                .yellow()
+                .to_string(),
+        );
+        s.push_str("\n\n");
+
+        for solution in self.solutions.iter() {
+            s.push_str(&format!("    {solution}\n"));
+        }
+        s
+    }
+}
+
+/// Count the tiles that are needed from the rack for each letter and how many
+/// of those must be covered by blanks.
+fn blanks_needed(letters: &[char], rack: &[char]) -> HashMap<char, usize> {
+    let mut available = HashMap::new();
+    for tile in rack.iter().filter(|c| **c != BLANK) {
+        *available.entry(*tile).or_insert(0) += 1;
+    }
+
+    let mut needed = HashMap::new();
+    for letter in letters {
+        match available.get_mut(letter) {
+            Some(count) if *count > 0 => *count -= 1,
+            _ => *needed.entry(*letter).or_insert(0) += 1,
+        }
+    }
+    needed
+}
+
+/// Score a word made entirely from the rack, with blanks scoring nothing.
+fn rack_score(word: &str, rack: &[char]) -> u32 {
+    let letters = word.chars().collect::<Vec<char>>();
+    let blanks = blanks_needed(&letters, rack);
+
+    let mut score = letters.iter().map(|c| letter_value(*c)).sum::<u32>();
+    for (letter, count) in blanks {
+        score -= letter_value(letter) * count as u32;
+    }
+    if letters.len() == RACK_SIZE {
+        score += BINGO_BONUS;
+    }
+    score
+}
+
+/// Find the highest scoring position for the word on the board line.
+fn best_placement(word: &str, rack: &[char], board: &[Square]) -> Option<Play> {
+    let letters = word.chars().collect::<Vec<char>>();
+    if letters.len() > board.len() {
+        return None;
+    }
+    let board_has_tiles = board.iter().any(|s| matches!(s, Square::Tile(_)));
+
+    let mut best: Option<Play> = None;
+    for start in 0..=(board.len() - letters.len()) {
+        let Some(score) = placement_score(&letters, rack, board, start, board_has_tiles) else {
+            continue;
+        };
+        if best.as_ref().is_none_or(|b| score > b.score) {
+            best = Some(Play {
+                word: word.to_string(),
+                score,
+                position: Some(start),
+            });
+        }
+    }
+    best
+}
+
+fn placement_score(
+    letters: &[char],
+    rack: &[char],
+    board: &[Square],
+    start: usize,
+    board_has_tiles: bool,
+) -> Option<u32> {
+    let end = start + letters.len();
+
+    // The word must not run into tiles either side of it
+    if start > 0 && matches!(board[start - 1], Square::Tile(_)) {
+        return None;
+    }
+    if end < board.len() && matches!(board[end], Square::Tile(_)) {
+        return None;
+    }
+
+    let mut placed = Vec::new();
+    let mut touches_tile = false;
+    let mut score = 0;
+    let mut word_multiplier = 1;
+    for (letter, square) in letters.iter().zip(&board[start..end]) {
+        match square {
+            Square::Tile(tile) => {
+                if tile != letter {
+                    return None;
+                }
+                touches_tile = true;
+                score += letter_value(*letter);
+            }
+            Square::Empty(premium) => {
+                placed.push((*letter, *premium));
+                word_multiplier *= premium.word_multiplier();
+            }
+        }
+    }
+
+    if placed.is_empty() || placed.len() > rack.len() || (board_has_tiles && !touches_tile) {
+        return None;
+    }
+
+    let placed_letters = placed.iter().map(|(c, _)| *c).collect::<Vec<char>>();
+    let mut blanks = blanks_needed(&placed_letters, rack);
+    if blanks.values().sum::<usize>() > rack.iter().filter(|c| **c == BLANK).count() {
+        return None;
+    }
+
+    // Blanks are put on the squares where the letter would have scored least
+    placed.sort_by_key(|(c, p)| letter_value(*c) * p.letter_multiplier());
+    for (letter, premium) in placed.iter() {
+        if let Some(count) = blanks.get_mut(letter) {
+            if *count > 0 {
+                *count -= 1;
+                continue;
+            }
+        }
+        score += letter_value(*letter) * premium.letter_multiplier();
+    }
+
+    score *= word_multiplier;
+    if placed.len() == RACK_SIZE {
+        score += BINGO_BONUS;
+    }
+    Some(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rack_score_with_blanks() {
+        let rack = "qi?zeta".chars().collect::<Vec<char>>();
+        assert_eq!(rack_score("quiz", &rack), 21);
+        assert_eq!(rack_score("quiet", &rack), 13);
+    }
+
+    #[test]
+    fn test_rack_solutions_sorted_by_score() {
+        let words = WordList::from_words(["quiz", "zeta", "quartz", "quiet", "tea"]);
+        let mut scrabble = Scrabble::new("qi?zeta", &words).unwrap();
+        scrabble.find_solutions().unwrap();
+
+        let words = scrabble
+            .solutions()
+            .iter()
+            .map(|p| (p.word.as_str(), p.score))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            words,
+            vec![("quiz", 21), ("quiet", 13), ("zeta", 13), ("tea", 3)]
+        );
+    }
+
+    #[test]
+    fn test_board_placement() {
+        let words = WordList::from_words(["zeta", "beta", "zebra", "eat"]);
+        let mut scrabble = Scrabble::new("ztab", &words).unwrap();
+        scrabble
+            .set_board(Some("..3.eD..".to_string()))
+            .unwrap()
+            .find_solutions()
+            .unwrap();
+
+        let best = &scrabble.solutions()[0];
+        assert_eq!(best.word, "zeta");
+        assert_eq!(best.position, Some(3));
+        assert_eq!(best.score, 26);
+        // `zebra` cannot be played as the rack has no `r` and there is no blank
+        assert!(scrabble.solutions().iter().all(|p| p.word != "zebra"));
+    }
+
+    #[test]
+    fn test_board_rejects_unknown_square() {
+        assert_eq!(Square::parse_line("..x!"), Err(Error::ScrabbleBoard('!')));
+    }
+}
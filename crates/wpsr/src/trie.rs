@@ -0,0 +1,221 @@
+use crate::WordList;
+
+const NO_WORD: u32 = u32::MAX;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct Node {
+    first_edge: u32,
+    edge_count: u32,
+    word: u32,
+}
+
+/// A prefix tree over a word list for solvers that build words a letter at
+/// a time and want to stop as soon as no word can start the way they have.
+///
+/// Nodes are numbered from the root at `0`, and the edges out of each node
+/// are stored together, sorted by letter, so the whole tree is two flat
+/// arrays. Each word keeps its position in the list it was built from.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Trie {
+    nodes: Vec<Node>,
+    edges: Vec<(char, u32)>,
+    words: usize,
+}
+
+impl Trie {
+    pub fn new(words: &WordList) -> Self {
+        Self::from_words(words.iter())
+    }
+
+    /// Build a trie from words in memory. Later copies of a word are ignored.
+    pub fn from_words<I, S>(words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut children: Vec<Vec<(char, u32)>> = vec![Vec::new()];
+        let mut ids = vec![NO_WORD];
+        let mut count = 0;
+
+        for word in words {
+            let mut node = 0;
+            for letter in word.as_ref().chars() {
+                node = match children[node].iter().find(|(c, _)| *c == letter) {
+                    Some((_, next)) => *next as usize,
+                    None => {
+                        let next = children.len();
+                        children.push(Vec::new());
+                        ids.push(NO_WORD);
+                        children[node].push((letter, next as u32));
+                        next
+                    }
+                };
+            }
+            if ids[node] == NO_WORD {
+                ids[node] = count;
+                count += 1;
+            }
+        }
+
+        let mut nodes = Vec::with_capacity(children.len());
+        let mut edges = Vec::with_capacity(children.len() - 1);
+        for (mut out, word) in children.into_iter().zip(ids) {
+            out.sort_unstable();
+            nodes.push(Node {
+                first_edge: edges.len() as u32,
+                edge_count: out.len() as u32,
+                word,
+            });
+            edges.extend(out);
+        }
+
+        Self {
+            nodes,
+            edges,
+            words: count as usize,
+        }
+    }
+
+    /// The number of words in the trie.
+    pub fn len(&self) -> usize {
+        self.words
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words == 0
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// The node every word starts from.
+    pub fn root(&self) -> usize {
+        0
+    }
+
+    /// The node reached from `node` by the given letter.
+    pub fn step(&self, node: usize, letter: char) -> Option<usize> {
+        let node = self.nodes.get(node)?;
+        let start = node.first_edge as usize;
+        self.edges[start..start + node.edge_count as usize]
+            .iter()
+            .find(|(c, _)| *c == letter)
+            .map(|(_, next)| *next as usize)
+    }
+
+    /// The node reached from `node` by each letter of `letters` in turn.
+    pub fn walk(&self, node: usize, letters: &str) -> Option<usize> {
+        letters
+            .chars()
+            .try_fold(node, |node, letter| self.step(node, letter))
+    }
+
+    /// True when the letters leading to `node` make a word.
+    pub fn is_word(&self, node: usize) -> bool {
+        self.nodes.get(node).is_some_and(|n| n.word != NO_WORD)
+    }
+
+    pub fn contains(&self, word: &str) -> bool {
+        self.walk(self.root(), word)
+            .is_some_and(|node| self.is_word(node))
+    }
+
+    /// True when at least one word starts with the prefix.
+    pub fn is_prefix(&self, prefix: &str) -> bool {
+        self.walk(self.root(), prefix).is_some()
+    }
+
+    /// Every word starting with the prefix, in the order the words were
+    /// added to the trie.
+    pub fn words_with_prefix(&self, prefix: &str) -> Vec<String> {
+        let Some(start) = self.walk(self.root(), prefix) else {
+            return Vec::new();
+        };
+
+        let mut found = Vec::new();
+        let mut stack = vec![(start, prefix.to_string())];
+        while let Some((node, word)) = stack.pop() {
+            let n = self.nodes[node];
+            if n.word != NO_WORD {
+                found.push((n.word, word.clone()));
+            }
+            let first = n.first_edge as usize;
+            for (letter, next) in self.edges[first..first + n.edge_count as usize].iter() {
+                let mut next_word = word.clone();
+                next_word.push(*letter);
+                stack.push((*next as usize, next_word));
+            }
+        }
+
+        found.sort_unstable_by_key(|(id, _)| *id);
+        found.into_iter().map(|(_, word)| word).collect()
+    }
+
+    /// Positions of the words as long as the pattern that have its letters
+    /// in place, where `None` stands for any letter, in list order.
+    pub fn matching(&self, pattern: &[Option<char>]) -> Vec<usize> {
+        let mut found = Vec::new();
+        let mut stack = vec![(self.root(), 0)];
+        while let Some((node, depth)) = stack.pop() {
+            let n = self.nodes[node];
+            if depth == pattern.len() {
+                if n.word != NO_WORD {
+                    found.push(n.word as usize);
+                }
+                continue;
+            }
+            let first = n.first_edge as usize;
+            for (letter, next) in self.edges[first..first + n.edge_count as usize].iter() {
+                if pattern[depth].is_none_or(|l| l == *letter) {
+                    stack.push((*next as usize, depth + 1));
+                }
+            }
+        }
+
+        found.sort_unstable();
+        found
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trie() -> Trie {
+        Trie::from_words(["tea", "ten", "to", "inn", "tea", "in", "tent"])
+    }
+
+    #[test]
+    fn test_lookups() {
+        let trie = trie();
+
+        assert_eq!(trie.len(), 6);
+        assert!(trie.contains("ten"));
+        assert!(!trie.contains("te"));
+        assert!(trie.is_prefix("te"));
+        assert!(!trie.is_prefix("tz"));
+        let node = trie.walk(trie.root(), "in").unwrap();
+        assert!(trie.is_word(node));
+        assert!(trie.step(node, 'n').is_some_and(|n| trie.is_word(n)));
+    }
+
+    #[test]
+    fn test_words_with_prefix_keep_list_order() {
+        let trie = trie();
+
+        assert_eq!(trie.words_with_prefix("te"), ["tea", "ten", "tent"]);
+        assert_eq!(trie.words_with_prefix("i"), ["inn", "in"]);
+        assert_eq!(trie.words_with_prefix("x"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_matching_pattern() {
+        let trie = trie();
+
+        assert_eq!(trie.matching(&[Some('t'), Some('e'), None]), [0, 1]);
+        assert_eq!(trie.matching(&[None, None, Some('n')]), [1, 3]);
+        assert_eq!(trie.matching(&[None, None]), [2, 4]);
+        assert_eq!(trie.matching(&[Some('x'), None]), Vec::<usize>::new());
+    }
+}
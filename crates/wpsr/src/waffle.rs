@@ -0,0 +1,565 @@
+use std::collections::{BTreeMap, HashMap};
+
+use colorful::Colorful;
+
+use crate::{Error, WordList};
+
+const SIZE: usize = 5;
+const TILES: usize = 21;
+
+/// Colour of a tile in the puzzle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Colour {
+    /// Right letter in the right place
+    Green,
+    /// Letter belongs elsewhere in a word through this tile
+    Yellow,
+    /// Letter is not wanted elsewhere in the words through this tile
+    Grey,
+}
+
+impl Colour {
+    fn parse(c: char) -> Option<Self> {
+        match c.to_ascii_lowercase() {
+            'g' => Some(Self::Green),
+            'y' => Some(Self::Yellow),
+            'b' | 'w' | '.' | '-' => Some(Self::Grey),
+            _ => None,
+        }
+    }
+}
+
+/// A swap of the letters on two tiles, given as `(row, column)` from zero.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Swap {
+    pub from: (usize, usize),
+    pub to: (usize, usize),
+}
+
+/// Grid cells that hold a tile, in reading order. The four cells between the
+/// words are holes.
+fn cells() -> Vec<(usize, usize)> {
+    (0..SIZE)
+        .flat_map(|r| (0..SIZE).map(move |c| (r, c)))
+        .filter(|(r, c)| r % 2 == 0 || c % 2 == 0)
+        .collect()
+}
+
+fn tile_at(row: usize, column: usize) -> usize {
+    cells()
+        .iter()
+        .position(|cell| *cell == (row, column))
+        .unwrap()
+}
+
+/// The tiles of the six words: three rows then three columns.
+fn slots() -> Vec<[usize; SIZE]> {
+    let mut slots = Vec::new();
+    for r in [0, 2, 4] {
+        slots.push(std::array::from_fn(|c| tile_at(r, c)));
+    }
+    for c in [0, 2, 4] {
+        slots.push(std::array::from_fn(|r| tile_at(r, c)));
+    }
+    slots
+}
+
+/// Read 21 tile values, or 25 with any character standing in the holes,
+/// ignoring `/`, `,` and whitespace between rows.
+fn parse_tiles(value: &str) -> Option<Vec<char>> {
+    let chars = value
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '/' && *c != ',')
+        .map(|c| c.to_ascii_lowercase())
+        .collect::<Vec<char>>();
+    match chars.len() {
+        TILES => Some(chars),
+        n if n == SIZE * SIZE => Some(
+            chars
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| (i / SIZE) % 2 == 0 || (i % SIZE) % 2 == 0)
+                .map(|(_, c)| *c)
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
+#[derive(Debug)]
+pub struct Waffle<'a> {
+    letters: Vec<char>,
+    colours: Vec<Colour>,
+    words: &'a WordList,
+    max: usize,
+    solutions: Vec<Vec<char>>,
+    swaps: Vec<Swap>,
+}
+
+impl<'a> Waffle<'a> {
+    /// The letters of the puzzle and their colours, each given row by row.
+    pub fn new(grid: &str, colours: &str, words: &'a WordList) -> Result<Self, Error> {
+        let letters = parse_tiles(grid)
+            .filter(|l| l.iter().all(|c| c.is_ascii_lowercase()))
+            .ok_or(Error::WaffleGrid(grid.to_string()))?;
+        let colours = parse_tiles(colours)
+            .and_then(|c| c.into_iter().map(Colour::parse).collect::<Option<Vec<_>>>())
+            .ok_or(Error::WaffleColours(colours.to_string()))?;
+
+        Ok(Self {
+            words,
+            letters,
+            colours,
+            max: 5,
+            solutions: Vec::new(),
+            swaps: Vec::new(),
+        })
+    }
+
+    pub fn set_max_solutions(&mut self, value: usize) -> &mut Self {
+        self.max = value;
+        self
+    }
+
+    pub fn solutions(&self) -> &[Vec<char>] {
+        &self.solutions
+    }
+
+    pub fn swaps(&self) -> &[Swap] {
+        &self.swaps
+    }
+
+    /// Find the six words that rearrange the tiles consistently with the
+    /// colours, then plan the fewest swaps to reach the first solution.
+    #[tracing::instrument(skip(self))]
+    pub fn find_solutions(&mut self) -> Result<&mut Self, Error> {
+        let mut available = [0i32; 26];
+        for letter in self.letters.iter() {
+            available[(*letter as u8 - b'a') as usize] += 1;
+        }
+
+        let mut words = self
+            .words
+            .iter()
+            .map(|w| w.to_lowercase())
+            .filter(|w| w.len() == SIZE && w.chars().all(|c| c.is_ascii_lowercase()))
+            .collect::<Vec<String>>();
+        words.sort();
+        words.dedup();
+
+        let slots = slots();
+        let candidates = slots
+            .iter()
+            .map(|slot| {
+                words
+                    .iter()
+                    .filter(|word| self.fits_slot(slot, word, &available))
+                    .map(|word| word.chars().collect::<Vec<char>>())
+                    .collect::<Vec<Vec<char>>>()
+            })
+            .collect::<Vec<_>>();
+        tracing::debug!(
+            "Candidates per word: {:?}",
+            candidates.iter().map(|c| c.len()).collect::<Vec<_>>()
+        );
+
+        let mut solutions = Vec::new();
+        let mut assigned = vec![None; TILES];
+        self.place(
+            0,
+            &slots,
+            &candidates,
+            &mut assigned,
+            &mut available,
+            &mut solutions,
+        );
+
+        let Some(solution) = solutions.first() else {
+            return Err(Error::WaffleUnsolved);
+        };
+        self.swaps = plan_swaps(&self.letters, solution)?;
+        self.solutions = solutions;
+
+        Ok(self)
+    }
+
+    /// Whether the word can fill the slot given the greens, the tiles that
+    /// are known to be wrong, and the letters on the board.
+    fn fits_slot(&self, slot: &[usize; SIZE], word: &str, available: &[i32; 26]) -> bool {
+        let mut counts = [0i32; 26];
+        for (tile, letter) in slot.iter().zip(word.chars()) {
+            let green = self.colours[*tile] == Colour::Green;
+            if green != (self.letters[*tile] == letter) {
+                return false;
+            }
+            counts[(letter as u8 - b'a') as usize] += 1;
+        }
+        counts.iter().zip(available.iter()).all(|(c, a)| c <= a)
+    }
+
+    fn place(
+        &self,
+        slot: usize,
+        slots: &[[usize; SIZE]],
+        candidates: &[Vec<Vec<char>>],
+        assigned: &mut Vec<Option<char>>,
+        available: &mut [i32; 26],
+        solutions: &mut Vec<Vec<char>>,
+    ) {
+        if solutions.len() >= self.max {
+            return;
+        }
+        if slot == slots.len() {
+            let solution = assigned.iter().map(|a| a.unwrap()).collect::<Vec<char>>();
+            if self.matches_colours(&solution) && !solutions.contains(&solution) {
+                solutions.push(solution);
+            }
+            return;
+        }
+
+        for word in candidates[slot].iter() {
+            let mut placed = Vec::new();
+            let mut fits = true;
+            for (tile, letter) in slots[slot].iter().zip(word.iter()) {
+                match assigned[*tile] {
+                    Some(existing) if existing != *letter => fits = false,
+                    Some(_) => {}
+                    None => {
+                        let count = &mut available[(*letter as u8 - b'a') as usize];
+                        if *count == 0 {
+                            fits = false;
+                        } else {
+                            *count -= 1;
+                            assigned[*tile] = Some(*letter);
+                            placed.push(*tile);
+                        }
+                    }
+                }
+                if !fits {
+                    break;
+                }
+            }
+
+            if fits {
+                self.place(slot + 1, slots, candidates, assigned, available, solutions);
+            }
+            for tile in placed {
+                if let Some(letter) = assigned[tile].take() {
+                    available[(letter as u8 - b'a') as usize] += 1;
+                }
+            }
+        }
+    }
+
+    /// Check the yellow and grey tiles against a complete solution. A yellow
+    /// letter must be wanted at another unsolved place in one of its words. A
+    /// grey letter may only be wanted at unsolved places in its words as often
+    /// as yellow tiles of that letter in the same words account for.
+    fn matches_colours(&self, solution: &[char]) -> bool {
+        let slots = slots();
+        let through = |tile: usize| {
+            slots
+                .iter()
+                .filter(move |slot| slot.contains(&tile))
+                .collect::<Vec<_>>()
+        };
+        let wanted = |slot: &[usize; SIZE], letter: char| {
+            slot.iter()
+                .filter(|t| self.colours[**t] != Colour::Green && solution[**t] == letter)
+                .count()
+        };
+
+        for tile in 0..TILES {
+            let letter = self.letters[tile];
+            match self.colours[tile] {
+                Colour::Green => {}
+                Colour::Yellow => {
+                    if through(tile).iter().all(|slot| wanted(slot, letter) == 0) {
+                        return false;
+                    }
+                }
+                Colour::Grey => {
+                    for slot in through(tile) {
+                        let yellows = slot
+                            .iter()
+                            .filter(|t| {
+                                self.colours[**t] == Colour::Yellow && self.letters[**t] == letter
+                            })
+                            .count();
+                        if wanted(slot, letter) > yellows {
+                            return false;
+                        }
+                    }
+                }
+            }
+        }
+        true
+    }
+
+    pub fn word_source_string(&self) -> String {
+        self.words.source_string()
+    }
+
+    pub fn solutions_title(&self) -> String {
+        let intro = "Waffle";
+        let mut ul = String::new();
+        for _ in 0..intro.len() {
+            ul.push('‾');
+        }
+
+        format!(
+            "{}\n{}\n\n{}",
+            intro.yellow().bold(),
+            ul.bold().yellow(),
+            grid_string(&self.letters).blue().bold()
+        )
+    }
+
+    pub fn solutions_string(&self) -> String {
+        let mut s = String::new();
+
+        for (index, solution) in self.solutions.iter().enumerate() {
+            s.push_str("  ");
+            s.push_str(
+                &format!("Solution {}", index + 1)
+                    .underlined()
+                    .yellow()
+                    .to_string(),
+            );
+            s.push_str("\n\n");
+            s.push_str(&grid_string(solution));
+            let words = slots()
+                .iter()
+                .map(|slot| slot.iter().map(|t| solution[*t]).collect::<String>())
+                .collect::<Vec<String>>();
+            s.push_str(&format!("\n    {}\n\n", words.join(" ")));
+        }
+
+        s.push_str("  ");
+        s.push_str(
+            &format!("{} swaps to reach solution 1", self.swaps.len())
+                .underlined()
+                .yellow()
+                .to_string(),
+        );
+        s.push_str("\n\n");
+        let mut letters = self.letters.clone();
+        for (index, swap) in self.swaps.iter().enumerate() {
+            let from = tile_at(swap.from.0, swap.from.1);
+            let to = tile_at(swap.to.0, swap.to.1);
+            s.push_str(&format!(
+                "  {:2}. {} at row {}, column {} with {} at row {}, column {}\n",
+                index + 1,
+                letters[from].to_ascii_uppercase(),
+                swap.from.0 + 1,
+                swap.from.1 + 1,
+                letters[to].to_ascii_uppercase(),
+                swap.to.0 + 1,
+                swap.to.1 + 1
+            ));
+            letters.swap(from, to);
+        }
+        s
+    }
+}
+
+fn grid_string(letters: &[char]) -> String {
+    let mut rows = vec![vec![' '; SIZE]; SIZE];
+    for ((r, c), letter) in cells().iter().zip(letters.iter()) {
+        rows[*r][*c] = letter.to_ascii_uppercase();
+    }
+    rows.iter()
+        .map(|row| {
+            let row = row.iter().map(|c| c.to_string()).collect::<Vec<_>>();
+            format!("    {}\n", row.join(" ").trim_end())
+        })
+        .collect()
+}
+
+/// The fewest swaps that turn the current letters into the target. Every
+/// misplaced tile points at the misplaced tiles that want its letter, and a
+/// cycle of `k` tiles takes `k - 1` swaps, so the misplaced tiles are split
+/// into as many cycles as they can be.
+fn plan_swaps(current: &[char], target: &[char]) -> Result<Vec<Swap>, Error> {
+    let cells = cells();
+    let open = (0..current.len())
+        .filter(|t| current[*t] != target[*t])
+        .fold(0, |open, t| open | 1 << t);
+    let cycles =
+        most_cycles(open, current, target, &mut HashMap::new()).ok_or(Error::WaffleSwaps)?;
+
+    let mut swaps = Vec::new();
+    for cycle in cycles {
+        // The letter on each tile belongs on the next tile of the cycle.
+        for next in cycle.iter().skip(1) {
+            swaps.push(Swap {
+                from: cells[cycle[0]],
+                to: cells[*next],
+            });
+        }
+    }
+    Ok(swaps)
+}
+
+type Cycles = Vec<Vec<usize>>;
+
+/// The split of the open tiles, one bit a tile, into the most cycles, or
+/// `None` when they cannot be split into cycles at all. The lowest open tile
+/// is in one of the cycles, so each cycle through it is tried in turn.
+fn most_cycles(
+    open: u32,
+    current: &[char],
+    target: &[char],
+    known: &mut HashMap<u32, Option<Cycles>>,
+) -> Option<Cycles> {
+    if open == 0 {
+        return Some(Vec::new());
+    }
+    if let Some(cycles) = known.get(&open) {
+        return cycles.clone();
+    }
+
+    let mut best: Option<Cycles> = None;
+    for cycle in cycles_through(open.trailing_zeros() as usize, open, current, target) {
+        let rest = cycle.iter().fold(open, |rest, t| rest & !(1 << t));
+        if let Some(mut cycles) = most_cycles(rest, current, target, known)
+            && best.as_ref().is_none_or(|b| cycles.len() + 1 > b.len())
+        {
+            cycles.insert(0, cycle);
+            best = Some(cycles);
+        }
+    }
+    known.insert(open, best.clone());
+    best
+}
+
+/// Every cycle of open tiles through `start`, one for each set of tiles,
+/// shortest first.
+fn cycles_through(start: usize, open: u32, current: &[char], target: &[char]) -> Cycles {
+    let mut cycles = BTreeMap::new();
+    let mut path = vec![start];
+    extend_cycle(&mut path, 1 << start, open, current, target, &mut cycles);
+
+    let mut cycles = cycles.into_values().collect::<Cycles>();
+    cycles.sort_by_key(|c| c.len());
+    cycles
+}
+
+fn extend_cycle(
+    path: &mut Vec<usize>,
+    visited: u32,
+    open: u32,
+    current: &[char],
+    target: &[char],
+    cycles: &mut BTreeMap<u32, Vec<usize>>,
+) {
+    let tile = path[path.len() - 1];
+    for next in (0..current.len()).filter(|n| open & (1 << n) != 0 && target[*n] == current[tile]) {
+        if next == path[0] {
+            cycles.entry(visited).or_insert_with(|| path.clone());
+        } else if visited & (1 << next) == 0 {
+            path.push(next);
+            extend_cycle(path, visited | 1 << next, open, current, target, cycles);
+            path.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The solved waffle is
+    //
+    //   S T A R T
+    //   H . R . R
+    //   E A G L E
+    //   L . U . S
+    //   L E E R S
+    //
+    // with its rows start, eagle, leers and columns shell, argue, tress.
+    const SOLVED: &str = "start/h.r.r/eagle/l.u.s/leers";
+
+    #[test]
+    fn test_parse_tiles() {
+        assert_eq!(parse_tiles(SOLVED).unwrap().len(), TILES);
+        assert_eq!(parse_tiles("starthrreaglelusleers"), parse_tiles(SOLVED));
+        assert!(parse_tiles("starthrreaglelusleersx").is_none());
+        assert!(parse_tiles("start").is_none());
+        assert_eq!(
+            Waffle::new("start", "ggggg", &WordList::default()).err(),
+            Some(Error::WaffleGrid("start".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_plan_swaps_splits_into_cycles() {
+        let target = parse_tiles(SOLVED).unwrap();
+        let mut current = target.clone();
+        // Swap two tiles, then rotate three others.
+        current.swap(0, 4);
+        let (a, b, c) = (current[6], current[9], current[13]);
+        current[6] = c;
+        current[9] = a;
+        current[13] = b;
+
+        let swaps = plan_swaps(&current, &target).unwrap();
+        assert_eq!(swaps.len(), 3);
+
+        for swap in swaps {
+            current.swap(
+                tile_at(swap.from.0, swap.from.1),
+                tile_at(swap.to.0, swap.to.1),
+            );
+        }
+        assert_eq!(current, target);
+    }
+
+    #[test]
+    fn test_plan_swaps_finds_the_most_cycles() {
+        // Taking p, q, r as one cycle first leaves a cycle of the other six
+        // tiles, seven swaps in all, where three cycles of three take six.
+        let target = "pqqrrabpc".chars().collect::<Vec<char>>();
+        let mut current = "qrapbpqcr".chars().collect::<Vec<char>>();
+
+        let swaps = plan_swaps(&current, &target).unwrap();
+        assert_eq!(swaps.len(), 6);
+
+        for swap in swaps {
+            current.swap(
+                tile_at(swap.from.0, swap.from.1),
+                tile_at(swap.to.0, swap.to.1),
+            );
+        }
+        assert_eq!(current, target);
+    }
+
+    #[test]
+    fn test_plan_swaps_needs_the_same_tiles() {
+        assert_eq!(
+            plan_swaps(&['a', 'b'], &['a', 'c']).err(),
+            Some(Error::WaffleSwaps)
+        );
+    }
+
+    #[test]
+    fn test_solve_waffle() {
+        let words = WordList::from_words([
+            "start", "eagle", "leers", "shell", "argue", "tress", "stare", "sheer", "great",
+            "tears", "rates", "later", "alert",
+        ]);
+        // start/eagle/leers with the T and R of start swapped, and the G and
+        // L of eagle swapped.
+        let mut waffle = Waffle::new(
+            "sratt/h.r.r/ealge/l.u.s/leers",
+            "gygyg/g.g.g/ggyyg/g.g.g/ggggg",
+            &words,
+        )
+        .unwrap();
+        waffle.find_solutions().unwrap();
+
+        assert_eq!(waffle.solutions().len(), 1);
+        assert_eq!(waffle.solutions()[0], parse_tiles(SOLVED).unwrap());
+        assert_eq!(waffle.swaps().len(), 2);
+    }
+}
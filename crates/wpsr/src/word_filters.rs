@@ -2,6 +2,8 @@
 
 use std::collections::HashMap;
 
+/// Filters over a plain list of words. [`WordList`](crate::WordList) has
+/// faster letter filters that use the masks it keeps for each word.
 pub trait WordFilters {
     fn filter_to_minimum_length(self, length: usize) -> Self;
     fn filter_no_repeated_letters(&mut self) -> &mut Self;
@@ -11,6 +13,7 @@ pub trait WordFilters {
     fn filter_includes_all_letters(self, include: &str) -> Self;
     fn filter_includes_same_letters(&mut self, include: &str) -> &mut Self;
     fn filter_includes_specific_letters_in_volume(self, include: &str) -> Self;
+    fn filter_matches_pattern(self, pattern: &str) -> Self;
 }
 
 impl WordFilters for Vec<String> {
@@ -158,10 +161,15 @@ impl WordFilters for Vec<String> {
         self
     }
 
+    /// Retain words that can be made from the letters, using each letter no
+    /// more often than it appears. A `?` is a blank that stands for any letter.
     fn filter_includes_specific_letters_in_volume(self, letters: &str) -> Self {
         let mut letter_distribution = HashMap::new();
+        let mut blanks = 0;
         for letter in letters.chars() {
-            if letter != ' ' {
+            if letter == '?' {
+                blanks += 1;
+            } else if letter != ' ' {
                 *letter_distribution.entry(letter).or_insert(0) += 1;
             }
         }
@@ -170,10 +178,13 @@ impl WordFilters for Vec<String> {
 
         for word in self {
             let mut test_dist = letter_distribution.clone();
+            let mut test_blanks = blanks;
             let mut good_word = true;
             for letter in word.chars() {
                 if test_dist.contains_key(&letter) && test_dist.get(&letter).unwrap() > &0 {
                     *test_dist.get_mut(&letter).unwrap() -= 1;
+                } else if test_blanks > 0 {
+                    test_blanks -= 1;
                 } else {
                     good_word = false;
                     break;
@@ -186,6 +197,32 @@ impl WordFilters for Vec<String> {
 
         output
     }
+
+    /// Retain words of the same length as the pattern with the same letter at
+    /// each known position. A `_` in the pattern matches any letter.
+    #[tracing::instrument(skip(self))]
+    fn filter_matches_pattern(self, pattern: &str) -> Self {
+        let pattern = pattern.chars().collect::<Vec<char>>();
+
+        let matches = self
+            .iter()
+            .filter(|word| {
+                word.chars().count() == pattern.len()
+                    && word
+                        .chars()
+                        .zip(pattern.iter())
+                        .all(|(letter, p)| *p == '_' || letter == *p)
+            })
+            .map(|word| word.to_string())
+            .collect::<Vec<String>>();
+
+        tracing::info!(
+            "Matching the pattern ({}) there are {} words",
+            pattern.iter().collect::<String>(),
+            matches.len()
+        );
+        matches
+    }
 }
 
 #[allow(dead_code)]
@@ -348,4 +385,27 @@ mod tests {
         let filtered = filtered.filter_includes_specific_letters_in_volume("abloserimpucftgn");
         assert_eq!(filtered, vec!["ab", "loser", "simper", "spirem"]);
     }
+
+    #[test]
+    fn test_filter_letters_in_volume_with_blanks() {
+        let words = ["ab", "loser", "all", "simper", "success", "treat"];
+
+        let words = words.iter().map(|w| w.to_string()).collect::<Vec<String>>();
+
+        let filtered = words.filter_includes_specific_letters_in_volume("aloser?");
+        assert_eq!(filtered, vec!["ab", "loser", "all"]);
+    }
+
+    #[test]
+    fn test_filter_matches_pattern() {
+        let words = ["baker", "cakes", "maker", "taken", "bake", "makers"];
+
+        let words = words.iter().map(|w| w.to_string()).collect::<Vec<String>>();
+
+        let filtered = words.filter_matches_pattern("_ake_");
+        assert_eq!(filtered, vec!["baker", "cakes", "maker", "taken"]);
+
+        let filtered = filtered.filter_matches_pattern("_a_er");
+        assert_eq!(filtered, vec!["baker", "maker"]);
+    }
 }
@@ -0,0 +1,1011 @@
+mod binary;
+mod check;
+mod kind;
+mod letters;
+mod stats;
+
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    fs::File,
+    io::{Read, Write},
+    path::Path,
+    str::FromStr,
+    sync::{Arc, OnceLock},
+};
+
+use colorful::Colorful;
+
+pub use binary::{BinaryWordList, MappedWordList, encode_binary};
+pub use check::ListCheck;
+pub(crate) use check::proper_nouns;
+pub use kind::WordKind;
+pub use letters::{LETTERS, LetterCounts, OTHER, letter_counts, letter_mask, signature};
+pub use stats::ListStats;
+
+use crate::{
+    DEFAULT_BOXED_SOURCE_FILE, DEFAULT_LINE_LENGTH, DEFAULT_SOURCE_DIR, DEFAULT_WORDS_SOURCE_FILE,
+    Error,
+};
+
+/// The ways a word list can be stored on disk.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ListFormat {
+    /// Words separated by spaces on lines of up to 3010 characters.
+    #[default]
+    Text,
+    /// The versioned binary format described in [`BinaryWordList`].
+    Binary,
+}
+
+impl FromStr for ListFormat {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "text" => Ok(Self::Text),
+            "binary" => Ok(Self::Binary),
+            _ => Err(format!("Invalid word list format: {s}")),
+        }
+    }
+}
+
+impl ListFormat {
+    /// The usual file extension for lists in this format.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Text => "txt",
+            Self::Binary => binary::EXTENSION,
+        }
+    }
+}
+
+impl Display for ListFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Text => write!(f, "text"),
+            Self::Binary => write!(f, "binary"),
+        }
+    }
+}
+
+/// A list of words loaded once and shared by reference between the solvers.
+///
+/// The words are kept in a store shared by every list filtered from this
+/// one, so a filter only records which words it kept and in what order. Use
+/// [`WordList::to_vec`] when a solver needs words of its own.
+///
+/// Each word has its [`letter_mask`] and [`letter_counts`], worked out once
+/// for the whole store the first time they are needed, so filtering by
+/// letters is a few bitwise operations a word.
+///
+/// Lists read from `word<TAB>count` lines also carry how often each word is
+/// used, so solvers can drop rare words or try the common ones first. Words
+/// keep the casing of the file, which shows the [`WordKind`] of each.
+#[derive(Debug, Clone, Default)]
+pub struct WordList {
+    source: String,
+    store: Arc<Store>,
+    /// Positions in the store of the words in the list, in list order, or
+    /// `None` when the list is every word in the store.
+    selection: Option<Arc<[u32]>>,
+}
+
+/// The words of a list and what is known about each, shared between a list
+/// and the lists filtered from it.
+#[derive(Debug, Default)]
+struct Store {
+    words: Words,
+    /// Worked out for every word the first time one is needed, unless the
+    /// words are mapped from a file that has them.
+    masks: OnceLock<Vec<u32>>,
+    counts: OnceLock<Vec<LetterCounts>>,
+}
+
+#[derive(Debug)]
+enum Words {
+    /// Words packed end to end in one string, with the start of each word
+    /// followed by the end of the last. Frequencies are empty when the list
+    /// has none, otherwise one for each word.
+    Packed {
+        strings: String,
+        offsets: Vec<u32>,
+        frequencies: Vec<u32>,
+    },
+    /// Words read in place from a binary list file.
+    Mapped(MappedWordList),
+}
+
+impl Default for Words {
+    fn default() -> Self {
+        Self::Packed {
+            strings: String::new(),
+            offsets: vec![0],
+            frequencies: Vec::new(),
+        }
+    }
+}
+
+impl Store {
+    fn packed<'a>(words: impl IntoIterator<Item = &'a str>, frequencies: Vec<u32>) -> Self {
+        let mut strings = String::new();
+        let mut offsets = vec![0];
+        for word in words {
+            strings.push_str(word);
+            offsets.push(strings.len() as u32);
+        }
+        Self::new(Words::Packed {
+            strings,
+            offsets,
+            frequencies,
+        })
+    }
+
+    fn new(words: Words) -> Self {
+        Self {
+            words,
+            masks: OnceLock::new(),
+            counts: OnceLock::new(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        match &self.words {
+            Words::Packed { offsets, .. } => offsets.len() - 1,
+            Words::Mapped(map) => map.view().len(),
+        }
+    }
+
+    fn word(&self, position: usize) -> &str {
+        match &self.words {
+            Words::Packed {
+                strings, offsets, ..
+            } => &strings[offsets[position] as usize..offsets[position + 1] as usize],
+            Words::Mapped(map) => map.view().get(position).unwrap_or_default(),
+        }
+    }
+
+    fn mask(&self, position: usize) -> u32 {
+        if let Words::Mapped(map) = &self.words {
+            if let Some(mask) = map.view().mask(position) {
+                return mask;
+            }
+        }
+        self.masks
+            .get_or_init(|| (0..self.len()).map(|p| letter_mask(self.word(p))).collect())[position]
+    }
+
+    fn counts(&self, position: usize) -> &LetterCounts {
+        &self.counts.get_or_init(|| {
+            (0..self.len())
+                .map(|p| letter_counts(self.word(p)))
+                .collect()
+        })[position]
+    }
+
+    /// The signature stored for the word, when the words are mapped from a
+    /// file that has them.
+    fn signature(&self, position: usize) -> Option<&str> {
+        match &self.words {
+            Words::Packed { .. } => None,
+            Words::Mapped(map) => map.view().signature(position),
+        }
+    }
+
+    fn has_frequencies(&self) -> bool {
+        match &self.words {
+            Words::Packed { frequencies, .. } => !frequencies.is_empty(),
+            Words::Mapped(map) => map.view().has_frequencies(),
+        }
+    }
+
+    fn frequency(&self, position: usize) -> Option<u32> {
+        match &self.words {
+            Words::Packed { frequencies, .. } => frequencies.get(position).copied(),
+            Words::Mapped(map) => map.view().frequency(position),
+        }
+    }
+}
+
+impl PartialEq for WordList {
+    fn eq(&self, other: &Self) -> bool {
+        self.source == other.source
+            && self.len() == other.len()
+            && self.iter().eq(other.iter())
+            && self.frequencies() == other.frequencies()
+    }
+}
+
+impl Eq for WordList {}
+
+impl WordList {
+    /// Build a list from words already in memory, dropping duplicates.
+    pub fn from_words<I, S>(words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut seen = HashSet::new();
+        let words = words
+            .into_iter()
+            .map(Into::into)
+            .filter(|w| seen.insert(w.clone()))
+            .collect::<Vec<String>>();
+        Self::packed(words.iter().map(String::as_str), Vec::new())
+    }
+
+    /// Build a list from words and how often each is used. The counts of
+    /// duplicates are added to the first copy of the word.
+    pub fn from_counted<I, S>(words: I) -> Self
+    where
+        I: IntoIterator<Item = (S, u32)>,
+        S: Into<String>,
+    {
+        Self::from_entries(words.into_iter().map(|(w, c)| (w, Some(c))))
+    }
+
+    /// Build a list from words that may have a count. The list has
+    /// frequencies when any word has one, and words without count as `0`.
+    pub(crate) fn from_entries<I, S>(entries: I) -> Self
+    where
+        I: IntoIterator<Item = (S, Option<u32>)>,
+        S: Into<String>,
+    {
+        let mut positions = HashMap::new();
+        let mut words = Vec::new();
+        let mut frequencies = Vec::new();
+        let mut counted = false;
+        for (word, count) in entries {
+            let word = word.into();
+            counted |= count.is_some();
+            match positions.get(&word) {
+                Some(&i) => {
+                    let total: &mut u32 = &mut frequencies[i];
+                    *total = total.saturating_add(count.unwrap_or_default());
+                }
+                None => {
+                    positions.insert(word.clone(), words.len());
+                    words.push(word);
+                    frequencies.push(count.unwrap_or_default());
+                }
+            }
+        }
+
+        if !counted {
+            frequencies.clear();
+        }
+        Self::packed(words.iter().map(String::as_str), frequencies)
+    }
+
+    /// Read words from text. A line holding a tab is one word followed by
+    /// how often it is used; any other line is whitespace separated words.
+    pub fn from_text(text: &str) -> Result<Self, Error> {
+        Ok(Self::from_entries(parse_entries(text)?))
+    }
+
+    fn packed<'a>(words: impl IntoIterator<Item = &'a str>, frequencies: Vec<u32>) -> Self {
+        Self::with_store(Store::packed(words, frequencies))
+    }
+
+    fn with_store(store: Store) -> Self {
+        Self {
+            source: String::new(),
+            store: Arc::new(store),
+            selection: None,
+        }
+    }
+
+    /// Read a list of words from a file, as for [`WordList::from_text`],
+    /// dropping duplicates but otherwise keeping the order of the file. Files
+    /// in the binary format are recognised by their header and memory mapped.
+    /// Their words, and the masks, signatures and counts stored with them, are
+    /// then read in place from the map without being parsed or copied.
+    pub fn load(path: &str) -> Result<Self, Error> {
+        tracing::info!("Using word list: {}", path);
+        let to_error = |e: std::io::Error| Error::WordListRead(path.to_string(), e.to_string());
+
+        let mut magic = [0; 4];
+        let read = File::open(path)
+            .and_then(|mut f| f.read(&mut magic))
+            .map_err(to_error)?;
+        let mut list = if binary::is_binary(&magic[..read]) {
+            Self::with_store(Store::new(Words::Mapped(MappedWordList::open(path)?)))
+        } else {
+            let text = std::fs::read_to_string(path).map_err(to_error)?;
+            Self::from_text(&text).map_err(|e| match e {
+                Error::WordListFormat(reason) => Error::WordListFormat(format!("{path}: {reason}")),
+                e => e,
+            })?
+        };
+        list.source = path.to_string();
+        tracing::debug!("Loaded {} words", list.len());
+        Ok(list)
+    }
+
+    /// Load the word list named by the `source_dir` and `source_words_file`
+    /// settings, with the directory or file replaced where given.
+    pub fn from_settings(
+        settings: &HashMap<String, String>,
+        dir: Option<String>,
+        file: Option<String>,
+    ) -> Result<Self, Error> {
+        Self::load(&source_path(
+            settings,
+            "source_words_file",
+            DEFAULT_WORDS_SOURCE_FILE,
+            dir,
+            file,
+        ))
+    }
+
+    /// Load the prepared boxed word list named by the `source_dir` and
+    /// `source_boxed_file` settings, with the directory or file replaced
+    /// where given.
+    pub fn boxed_from_settings(
+        settings: &HashMap<String, String>,
+        dir: Option<String>,
+        file: Option<String>,
+    ) -> Result<Self, Error> {
+        Self::load(&source_path(
+            settings,
+            "source_boxed_file",
+            DEFAULT_BOXED_SOURCE_FILE,
+            dir,
+            file,
+        ))
+    }
+
+    /// Add the words of the files named by the `allowlist` setting and then
+    /// remove the words of those named by the `blocklist` setting, so a word
+    /// in both stays out. A blocked word is removed however it is cased, as
+    /// `Wtf` or `WTF` as well as `wtf`. Each setting is a comma separated list
+    /// of paths. Allowed words the list does not have are counted as `0` in a
+    /// list with frequencies.
+    pub fn with_overlays(self, settings: &HashMap<String, String>) -> Result<Self, Error> {
+        let mut list = self;
+        for path in overlay_paths(settings, "allowlist") {
+            list = list.merge(&Self::load(path)?);
+        }
+        for path in overlay_paths(settings, "blocklist") {
+            let blocked = Self::load(path)?
+                .iter()
+                .map(|w| w.to_lowercase())
+                .collect::<HashSet<String>>();
+            list = list.filter(|w| !blocked.contains(&w.to_lowercase()));
+        }
+        Ok(list)
+    }
+
+    /// The file the words were read from, empty for lists built in memory.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    pub fn len(&self) -> usize {
+        self.selection
+            .as_ref()
+            .map_or(self.store.len(), |selection| selection.len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Where the word at `index` in the list is kept in the store.
+    fn position(&self, index: usize) -> usize {
+        self.selection
+            .as_ref()
+            .map_or(index, |selection| selection[index] as usize)
+    }
+
+    /// The word at `index`, panicking when the list is shorter.
+    pub fn word(&self, index: usize) -> &str {
+        self.store.word(self.position(index))
+    }
+
+    /// The words in list order, borrowed from the store.
+    pub fn iter(&self) -> impl Iterator<Item = &str> + '_ {
+        (0..self.len()).map(|i| self.word(i))
+    }
+
+    /// Copy the words out of the list.
+    pub fn to_vec(&self) -> Vec<String> {
+        self.iter().map(String::from).collect()
+    }
+
+    /// The [`letter_mask`] of the word at `index`.
+    pub fn mask(&self, index: usize) -> u32 {
+        self.store.mask(self.position(index))
+    }
+
+    /// The [`letter_counts`] of the word at `index`.
+    pub fn counts(&self, index: usize) -> &LetterCounts {
+        self.store.counts(self.position(index))
+    }
+
+    /// The letters of the word at `index` in sorted order, as stored in a
+    /// binary list when it has them.
+    pub fn signature(&self, index: usize) -> Cow<'_, str> {
+        let position = self.position(index);
+        match self.store.signature(position) {
+            Some(signature) => Cow::Borrowed(signature),
+            None => Cow::Owned(signature(self.store.word(position))),
+        }
+    }
+
+    pub fn has_frequencies(&self) -> bool {
+        self.store.has_frequencies()
+    }
+
+    /// How often the word at `index` is used, when the list has frequencies.
+    pub fn frequency(&self, index: usize) -> Option<u32> {
+        self.store.frequency(self.position(index))
+    }
+
+    /// How often each word is used, empty when the list has no frequencies.
+    pub fn frequencies(&self) -> Vec<u32> {
+        (0..self.len()).filter_map(|i| self.frequency(i)).collect()
+    }
+
+    /// A list of the words at the given indexes, in that order, sharing the
+    /// store and the source of this one.
+    fn select(&self, indexes: impl Iterator<Item = usize>) -> Self {
+        let selection = indexes
+            .map(|i| self.position(i) as u32)
+            .collect::<Arc<[u32]>>();
+        tracing::info!("Filtered to {} words", selection.len());
+        Self {
+            source: self.source.clone(),
+            store: Arc::clone(&self.store),
+            selection: Some(selection),
+        }
+    }
+
+    /// The words whose index passes the test.
+    fn retain_indexes(&self, keep: impl Fn(usize) -> bool) -> Self {
+        self.select((0..self.len()).filter(|i| keep(*i)))
+    }
+
+    /// Words that pass the test.
+    pub fn filter(&self, keep: impl Fn(&str) -> bool) -> Self {
+        self.retain_indexes(|i| keep(self.word(i)))
+    }
+
+    /// The words in lower case, with the counts of words that differ only
+    /// in case added together.
+    pub fn to_lowercase(&self) -> Self {
+        if !self.iter().any(|w| w.chars().any(char::is_uppercase)) {
+            return self.clone();
+        }
+        let lowered = self.iter().map(|w| w.to_lowercase());
+        let mut list = if self.has_frequencies() {
+            Self::from_counted(lowered.zip(self.frequencies()))
+        } else {
+            Self::from_words(lowered)
+        };
+        list.source = self.source.clone();
+        list
+    }
+
+    /// The words of this list followed by the words of the other that this
+    /// one lacks. The counts of words in both are added together.
+    pub fn merge(&self, other: &WordList) -> Self {
+        let mut list = Self::from_entries(self.entries().chain(other.entries()));
+        list.source = self.source.clone();
+        list
+    }
+
+    /// Words that are not in the other list.
+    pub fn subtract(&self, other: &WordList) -> Self {
+        let other = other.iter().collect::<HashSet<&str>>();
+        self.retain_indexes(|i| !other.contains(self.word(i)))
+    }
+
+    /// Words that are also in the other list.
+    pub fn intersect(&self, other: &WordList) -> Self {
+        let other = other.iter().collect::<HashSet<&str>>();
+        self.retain_indexes(|i| other.contains(self.word(i)))
+    }
+
+    /// Each word with its count when the list has frequencies.
+    fn entries(&self) -> impl Iterator<Item = (&str, Option<u32>)> {
+        (0..self.len()).map(|i| (self.word(i), self.frequency(i)))
+    }
+
+    /// One spelling of each word, ignoring case, with the counts of the
+    /// spellings added together. The spelling kept is the lower case one when
+    /// there is one, then a capitalised one, then one in capitals, so the
+    /// list still shows which words are proper nouns and acronyms.
+    pub fn fold_case(&self) -> Self {
+        let mut positions: HashMap<String, usize> = HashMap::new();
+        let mut entries: Vec<(&str, Option<u32>)> = Vec::new();
+        for (word, count) in self.entries() {
+            let key = word.to_lowercase();
+            match positions.get(&key) {
+                Some(&i) => {
+                    let entry = &mut entries[i];
+                    if case_rank(word) < case_rank(entry.0) {
+                        entry.0 = word;
+                    }
+                    if let (Some(total), Some(count)) = (&mut entry.1, count) {
+                        *total = total.saturating_add(count);
+                    }
+                }
+                None => {
+                    positions.insert(key, entries.len());
+                    entries.push((word, count));
+                }
+            }
+        }
+
+        let mut list = Self::from_entries(entries);
+        list.source = self.source.clone();
+        list
+    }
+
+    /// Words written in lower case, leaving out proper nouns, acronyms and
+    /// anything else with a capital letter.
+    pub fn common_words(&self) -> Self {
+        self.filter(|w| WordKind::of(w) == Some(WordKind::Common))
+    }
+
+    /// Words used at least `minimum` times.
+    pub fn with_min_frequency(&self, minimum: u32) -> Result<Self, Error> {
+        self.require_frequencies()?;
+        Ok(self.retain_indexes(|i| self.frequency(i).unwrap_or_default() >= minimum))
+    }
+
+    /// The words with the most used first. Words used equally often keep
+    /// their order.
+    pub fn most_common_first(&self) -> Result<Self, Error> {
+        self.require_frequencies()?;
+        let mut order = (0..self.len()).collect::<Vec<usize>>();
+        order.sort_by_key(|i| std::cmp::Reverse(self.frequency(*i)));
+        Ok(self.select(order.into_iter()))
+    }
+
+    /// The words in sorted order.
+    pub fn sorted(&self) -> Self {
+        let mut order = (0..self.len()).collect::<Vec<usize>>();
+        order.sort_by(|a, b| self.word(*a).cmp(self.word(*b)));
+        self.select(order.into_iter())
+    }
+
+    fn require_frequencies(&self) -> Result<(), Error> {
+        if self.has_frequencies() || self.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::NoFrequencies(self.source.clone()))
+        }
+    }
+
+    /// Words with none of the letters. Anything in `letters` other than `a`
+    /// to `z` is ignored, here and in the other letter filters.
+    pub fn excluding_letters(&self, letters: &str) -> Self {
+        let mask = letter_mask(letters) & LETTERS;
+        self.retain_indexes(|i| self.mask(i) & mask == 0)
+    }
+
+    /// Words with every one of the letters.
+    pub fn including_all_letters(&self, letters: &str) -> Self {
+        let mask = letter_mask(letters) & LETTERS;
+        self.retain_indexes(|i| self.mask(i) & mask == mask)
+    }
+
+    /// Words with at least one of the letters.
+    pub fn including_any_letters(&self, letters: &str) -> Self {
+        let mask = letter_mask(letters) & LETTERS;
+        self.retain_indexes(|i| self.mask(i) & mask != 0)
+    }
+
+    /// Words made from exactly the letters, in any order and in the same case.
+    pub fn anagrams_of(&self, letters: &str) -> Self {
+        let key = signature(letters);
+        self.retain_indexes(|i| self.word(i).len() == letters.len() && self.signature(i) == key)
+    }
+
+    /// Words made only from the letters, with no other characters.
+    pub fn only_letters(&self, letters: &str) -> Self {
+        let mask = letter_mask(letters) & LETTERS;
+        self.retain_indexes(|i| self.mask(i) & !mask == 0)
+    }
+
+    /// Words that can be made from the letters, using each letter no more
+    /// often than it appears. A `?` is a blank that stands for any letter.
+    pub fn fitting_letters(&self, letters: &str) -> Self {
+        let available = letter_counts(letters);
+        let blanks = letters.chars().filter(|c| *c == '?').count();
+        self.retain_indexes(|i| {
+            self.mask(i) & OTHER == 0
+                && self
+                    .counts(i)
+                    .iter()
+                    .zip(available.iter())
+                    .map(|(need, have)| need.saturating_sub(*have) as usize)
+                    .sum::<usize>()
+                    <= blanks
+        })
+    }
+
+    /// Write the words to a file as space separated lines, or as one
+    /// `word<TAB>count` line a word when the list has frequencies.
+    pub fn write_text(&self, path: &Path) -> Result<(), Error> {
+        let mut text = if self.has_frequencies() {
+            self.entries()
+                .map(|(word, count)| format!("{word}\t{}", count.unwrap_or_default()))
+                .collect::<Vec<String>>()
+                .join("\n")
+        } else {
+            words_to_lines(self.iter()).join("\n")
+        };
+        if !text.is_empty() {
+            text.push('\n');
+        }
+        self.write_bytes(path, text.as_bytes())
+    }
+
+    /// Write the words to a file in the binary format, sorted, with the
+    /// letter masks and signatures where asked for and any frequencies.
+    pub fn write_binary(&self, path: &Path, masks: bool, signatures: bool) -> Result<(), Error> {
+        let words = self.iter().collect::<Vec<&str>>();
+        let frequencies = self.frequencies();
+        self.write_bytes(
+            path,
+            &encode_binary(
+                &words,
+                self.has_frequencies().then_some(frequencies.as_slice()),
+                masks,
+                signatures,
+            ),
+        )
+    }
+
+    fn write_bytes(&self, path: &Path, bytes: &[u8]) -> Result<(), Error> {
+        let to_error =
+            |e: std::io::Error| Error::WordListWrite(path.display().to_string(), e.to_string());
+        File::create(path)
+            .and_then(|mut file| file.write_all(bytes))
+            .map_err(to_error)?;
+        tracing::info!("Wrote {} words to {}", self.len(), path.display());
+        Ok(())
+    }
+
+    pub fn source_string(&self) -> String {
+        let s1 = "Using words sourced from ".light_cyan().dim().to_string();
+        let s2 = self.source.clone().light_cyan().bold().to_string();
+        format!("{s1}{s2}")
+    }
+}
+
+/// Every word of a text or binary list file as it is stored, duplicates
+/// included.
+pub(crate) fn read_words(path: &str) -> Result<Vec<String>, Error> {
+    let to_error = |e: std::io::Error| Error::WordListRead(path.to_string(), e.to_string());
+
+    let mut magic = [0; 4];
+    let read = File::open(path)
+        .and_then(|mut f| f.read(&mut magic))
+        .map_err(to_error)?;
+    if binary::is_binary(&magic[..read]) {
+        let map = MappedWordList::open(path)?;
+        Ok(map.view().iter().map(String::from).collect())
+    } else {
+        let text = std::fs::read_to_string(path).map_err(to_error)?;
+        Ok(parse_entries(&text)?
+            .into_iter()
+            .map(|(w, _)| w.to_string())
+            .collect())
+    }
+}
+
+/// Split text into words, each with a count when it was given on a
+/// `word<TAB>count` line.
+pub(crate) fn parse_entries(text: &str) -> Result<Vec<(&str, Option<u32>)>, Error> {
+    let mut entries = Vec::new();
+    for (number, line) in text.lines().enumerate() {
+        match line.split_once('\t') {
+            Some((word, count)) => {
+                let count = count.trim().parse::<u32>().map_err(|_| {
+                    Error::WordListFormat(format!(
+                        "line {} has a count that is not a number",
+                        number + 1
+                    ))
+                })?;
+                if !word.trim().is_empty() {
+                    entries.push((word.trim(), Some(count)));
+                }
+            }
+            None => entries.extend(line.split_whitespace().map(|w| (w, None))),
+        }
+    }
+    Ok(entries)
+}
+
+fn words_to_lines<'a>(words: impl Iterator<Item = &'a str>) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+
+    for word in words {
+        if !line.is_empty() && line.len() + word.len() + 1 > DEFAULT_LINE_LENGTH {
+            lines.push(std::mem::take(&mut line));
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(word);
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+
+    lines
+}
+
+/// Orders the spellings of a word by the [`WordKind`] they show, with odd
+/// casings last.
+fn case_rank(word: &str) -> (bool, Option<WordKind>) {
+    let kind = WordKind::of(word);
+    (kind.is_none(), kind)
+}
+
+fn overlay_paths<'a>(settings: &'a HashMap<String, String>, key: &str) -> Vec<&'a str> {
+    settings.get(key).map_or(Vec::new(), |paths| {
+        paths
+            .split(',')
+            .map(str::trim)
+            .filter(|p| !p.is_empty())
+            .collect()
+    })
+}
+
+fn source_path(
+    settings: &HashMap<String, String>,
+    file_key: &str,
+    default_file: &str,
+    dir: Option<String>,
+    file: Option<String>,
+) -> String {
+    let src_directory = dir.unwrap_or_else(|| {
+        settings
+            .get("source_dir")
+            .map_or(DEFAULT_SOURCE_DIR, |v| v)
+            .to_string()
+    });
+    let src_file = file.unwrap_or_else(|| {
+        settings
+            .get(file_key)
+            .map_or(default_file, |v| v)
+            .to_string()
+    });
+
+    format!("{src_directory}/{src_file}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_duplicates_are_dropped_in_order() {
+        let list = WordList::from_words(["pear", "apple", "pear", "fig", "apple"]);
+
+        assert_eq!(list.to_vec(), ["pear", "apple", "fig"]);
+        assert_eq!(list.len(), 3);
+    }
+
+    #[test]
+    fn test_letter_filters() {
+        let list = WordList::from_words(["ab", "loser", "all", "Treat", "greatness", "tea-set"]);
+
+        assert_eq!(
+            list.excluding_letters("cl").to_vec(),
+            ["ab", "Treat", "greatness", "tea-set"]
+        );
+        assert_eq!(
+            list.including_all_letters("ta").to_vec(),
+            ["Treat", "greatness", "tea-set"]
+        );
+        assert_eq!(list.including_any_letters("bo").to_vec(), ["ab", "loser"]);
+        assert_eq!(list.only_letters("tears").to_vec(), ["Treat"]);
+        assert_eq!(list.fitting_letters("allb").to_vec(), ["ab", "all"]);
+        assert_eq!(list.fitting_letters("tera?").to_vec(), ["ab", "Treat"]);
+
+        let filtered = list.excluding_letters("l");
+        assert!(
+            Arc::ptr_eq(&filtered.store, &list.store),
+            "filters share the words"
+        );
+        assert_eq!(filtered.mask(1), letter_mask("treat"));
+        assert_eq!(filtered.counts(1), &letter_counts("treat"));
+    }
+
+    #[test]
+    fn test_frequencies() {
+        let list = WordList::from_text("the\t90\nSeine\t4\nThe\t10\nzax\t1\n").unwrap();
+
+        assert_eq!(list.frequencies(), [90, 4, 10, 1]);
+        let lower = list.to_lowercase();
+        assert_eq!(lower.to_vec(), ["the", "seine", "zax"]);
+        assert_eq!(lower.frequencies(), [100, 4, 1]);
+        assert_eq!(
+            lower.with_min_frequency(4).unwrap().to_vec(),
+            ["the", "seine"]
+        );
+        assert_eq!(
+            list.most_common_first().unwrap().to_vec(),
+            ["the", "The", "Seine", "zax"]
+        );
+        assert_eq!(
+            lower.excluding_letters("h").frequencies(),
+            [4, 1],
+            "filters keep the frequencies"
+        );
+
+        let plain = WordList::from_text("the seine zax").unwrap();
+        assert!(!plain.has_frequencies());
+        assert_eq!(
+            plain.most_common_first().err(),
+            Some(Error::NoFrequencies(String::new()))
+        );
+        assert_eq!(
+            WordList::from_text("the\tmany").err(),
+            Some(Error::WordListFormat(
+                "line 1 has a count that is not a number".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_fold_case_keeps_kind() {
+        let list = WordList::from_counted([
+            ("Polish", 5),
+            ("ABEL", 1),
+            ("polish", 20),
+            ("Abel", 7),
+            ("NASA", 3),
+            ("abc", 2),
+        ]);
+
+        let folded = list.fold_case();
+        assert_eq!(folded.to_vec(), ["polish", "Abel", "NASA", "abc"]);
+        assert_eq!(folded.frequencies(), [25, 8, 3, 2]);
+        assert_eq!(folded.common_words().to_vec(), ["polish", "abc"]);
+        assert_eq!(
+            folded.to_lowercase().to_vec(),
+            ["polish", "abel", "nasa", "abc"]
+        );
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let base = WordList::from_counted([("pear", 3), ("apple", 5), ("fig", 1)]);
+        let other = WordList::from_words(["fig", "plum", "pear"]);
+
+        let merged = base.merge(&other);
+        assert_eq!(merged.to_vec(), ["pear", "apple", "fig", "plum"]);
+        assert_eq!(merged.frequencies(), [3, 5, 1, 0]);
+        assert_eq!(base.subtract(&other).to_vec(), ["apple"]);
+        assert_eq!(other.subtract(&base).to_vec(), ["plum"]);
+        assert_eq!(base.intersect(&other).to_vec(), ["pear", "fig"]);
+        assert_eq!(base.intersect(&other).frequencies(), [3, 1]);
+    }
+
+    #[test]
+    fn test_overlays_add_then_remove() {
+        let dir = std::env::temp_dir().join(format!("wpsr-overlays-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let mine = dir.join("mine.txt");
+        let rude = dir.join("rude.txt");
+        std::fs::write(&mine, "wpsr zax\n").unwrap();
+        std::fs::write(&rude, "fig zax\n").unwrap();
+        let settings = HashMap::from([
+            ("allowlist".to_string(), mine.display().to_string()),
+            ("blocklist".to_string(), format!(" {}, ", rude.display())),
+        ]);
+
+        let list = WordList::from_words(["pear", "fig", "apple"])
+            .with_overlays(&settings)
+            .unwrap();
+        assert_eq!(list.to_vec(), ["pear", "apple", "wpsr"]);
+        let cased = WordList::from_words(["Fig", "cat", "act", "FIG"])
+            .with_overlays(&settings)
+            .unwrap();
+        assert_eq!(cased.to_lowercase().to_vec(), ["cat", "act", "wpsr"]);
+        assert_eq!(
+            WordList::from_words(["pear"])
+                .with_overlays(&HashMap::new())
+                .unwrap()
+                .to_vec(),
+            ["pear"]
+        );
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_write_and_load_both_formats() {
+        let dir = std::env::temp_dir().join(format!("wpsr-word-list-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let list = WordList::from_words(["pear", "apple", "fig"]);
+
+        let text = dir.join("words.txt");
+        list.write_text(&text).unwrap();
+        assert_eq!(std::fs::read_to_string(&text).unwrap(), "pear apple fig\n");
+        let loaded = WordList::load(&text.display().to_string()).unwrap();
+        assert_eq!(loaded.to_vec(), list.to_vec());
+
+        let binary = dir.join("words.wlb");
+        list.write_binary(&binary, true, false).unwrap();
+        let loaded = WordList::load(&binary.display().to_string()).unwrap();
+        assert_eq!(loaded.to_vec(), ["apple", "fig", "pear"]);
+        assert!(matches!(loaded.signature(2), Cow::Owned(_)));
+
+        let mapped = WordList::from_words(["pear", "reap", "apple", "Pare"]);
+        mapped.write_binary(&binary, true, true).unwrap();
+        let loaded = WordList::load(&binary.display().to_string()).unwrap();
+        let filtered = loaded.excluding_letters("l");
+        assert_eq!(filtered.mask(1), letter_mask("pear"));
+        assert!(matches!(filtered.signature(1), Cow::Borrowed("aepr")));
+        assert_eq!(loaded.anagrams_of("pare").to_vec(), ["pear", "reap"]);
+
+        let counted = WordList::from_counted([("pear", 3), ("fig", 7)]);
+        counted.write_text(&text).unwrap();
+        assert_eq!(std::fs::read_to_string(&text).unwrap(), "pear\t3\nfig\t7\n");
+        assert_eq!(
+            WordList::load(&text.display().to_string())
+                .unwrap()
+                .frequencies(),
+            [3, 7]
+        );
+        counted.write_binary(&binary, false, false).unwrap();
+        assert_eq!(
+            WordList::load(&binary.display().to_string())
+                .unwrap()
+                .frequencies(),
+            [7, 3]
+        );
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_long_lists_are_split_into_lines() {
+        let words = (0..1000)
+            .map(|i| format!("word{i:04}"))
+            .collect::<Vec<String>>();
+        let lines = words_to_lines(words.iter().map(String::as_str));
+
+        assert!(lines.iter().all(|l| l.len() <= DEFAULT_LINE_LENGTH));
+        assert_eq!(lines.join(" ").split(' ').count(), 1000);
+    }
+
+    #[test]
+    fn test_source_path_prefers_overrides() {
+        let settings = HashMap::from([
+            ("source_dir".to_string(), "lists".to_string()),
+            ("source_words_file".to_string(), "big.txt".to_string()),
+        ]);
+
+        assert_eq!(
+            source_path(&settings, "source_words_file", "x.txt", None, None),
+            "lists/big.txt"
+        );
+        assert_eq!(
+            source_path(
+                &settings,
+                "source_boxed_file",
+                "default.slb",
+                Some("words".to_string()),
+                None
+            ),
+            "words/default.slb"
+        );
+        assert_eq!(
+            WordList::load("no/such/list.txt").err(),
+            Some(Error::WordListRead(
+                "no/such/list.txt".to_string(),
+                "No such file or directory (os error 2)".to_string()
+            ))
+        );
+    }
+}
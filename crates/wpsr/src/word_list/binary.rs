@@ -0,0 +1,392 @@
+//! A versioned binary word list that can be used straight from a memory map.
+//!
+//! All numbers are little endian `u32` unless noted.
+//!
+//! | Section    | Size                | Contents                                  |
+//! |------------|---------------------|-------------------------------------------|
+//! | header     | 16 bytes            | magic `WPWL`, version `u16`, flags `u16`, |
+//! |            |                     | word count, string table length           |
+//! | offsets    | 4 x (count + 1)     | start of each word in the string table    |
+//! | strings    | string table length | the words, sorted and packed as UTF-8     |
+//! | masks      | 4 x count           | [`letter_mask`] of each word (optional)   |
+//! | signatures | string table length | letters of each word sorted, at the same  |
+//! |            |                     | offsets as the words (optional)           |
+//! | counts     | 4 x count           | how often each word is used (optional)    |
+
+use std::{fs::File, ops::Range};
+
+use memmap2::Mmap;
+
+use super::letters::{letter_mask, signature};
+use crate::Error;
+
+pub const MAGIC: &[u8; 4] = b"WPWL";
+pub const VERSION: u16 = 1;
+pub const EXTENSION: &str = "wlb";
+
+const HAS_MASKS: u16 = 1;
+const HAS_SIGNATURES: u16 = 1 << 1;
+const HAS_FREQUENCIES: u16 = 1 << 2;
+const HEADER_LENGTH: usize = 16;
+
+/// True when the bytes start with the binary word list magic number.
+pub fn is_binary(bytes: &[u8]) -> bool {
+    bytes.starts_with(MAGIC)
+}
+
+/// Encode the words, sorted and without duplicates, with the optional
+/// letter masks and signatures, and how often each word is used when
+/// `frequencies` holds a count for each word. The counts of duplicates are
+/// added together.
+pub fn encode_binary<S: AsRef<str>>(
+    words: &[S],
+    frequencies: Option<&[u32]>,
+    masks: bool,
+    signatures: bool,
+) -> Vec<u8> {
+    let mut entries = words
+        .iter()
+        .enumerate()
+        .map(|(i, w)| (w.as_ref(), frequencies.map_or(0, |f| f[i])))
+        .collect::<Vec<(&str, u32)>>();
+    entries.sort_by_key(|(word, _)| *word);
+    entries.dedup_by(|(word, count), (kept, total)| {
+        let duplicate = word == kept;
+        if duplicate {
+            *total = total.saturating_add(*count);
+        }
+        duplicate
+    });
+    let (words, counts): (Vec<&str>, Vec<u32>) = entries.into_iter().unzip();
+
+    let strings = words.concat();
+    let mut flags = 0;
+    if masks {
+        flags |= HAS_MASKS;
+    }
+    if signatures {
+        flags |= HAS_SIGNATURES;
+    }
+    if frequencies.is_some() {
+        flags |= HAS_FREQUENCIES;
+    }
+
+    let mut bytes = Vec::with_capacity(HEADER_LENGTH + 4 * (words.len() + 1) + strings.len());
+    bytes.extend_from_slice(MAGIC);
+    bytes.extend_from_slice(&VERSION.to_le_bytes());
+    bytes.extend_from_slice(&flags.to_le_bytes());
+    bytes.extend_from_slice(&(words.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(&(strings.len() as u32).to_le_bytes());
+
+    let mut offset = 0;
+    bytes.extend_from_slice(&0u32.to_le_bytes());
+    for word in words.iter() {
+        offset += word.len() as u32;
+        bytes.extend_from_slice(&offset.to_le_bytes());
+    }
+    bytes.extend_from_slice(strings.as_bytes());
+
+    if masks {
+        for word in words.iter() {
+            bytes.extend_from_slice(&letter_mask(word).to_le_bytes());
+        }
+    }
+    if signatures {
+        for word in words.iter() {
+            bytes.extend_from_slice(signature(word).as_bytes());
+        }
+    }
+    if frequencies.is_some() {
+        for count in counts {
+            bytes.extend_from_slice(&count.to_le_bytes());
+        }
+    }
+
+    bytes
+}
+
+/// Where each section of an encoded list lies, found once when the bytes
+/// are checked so that views can be made again without checking them.
+#[derive(Debug, Clone)]
+struct Sections {
+    count: usize,
+    offsets: Range<usize>,
+    strings: Range<usize>,
+    masks: Option<Range<usize>>,
+    signatures: Option<Range<usize>>,
+    frequencies: Option<Range<usize>>,
+}
+
+impl Sections {
+    /// Check the header and the layout of the sections without copying them.
+    fn check(bytes: &[u8]) -> Result<Self, Error> {
+        let invalid = |reason: &str| Error::WordListFormat(reason.to_string());
+
+        if bytes.len() < HEADER_LENGTH || !is_binary(bytes) {
+            return Err(invalid("missing header"));
+        }
+        let version = u16::from_le_bytes([bytes[4], bytes[5]]);
+        if version != VERSION {
+            return Err(Error::WordListFormat(format!(
+                "unsupported version {version}"
+            )));
+        }
+        let flags = u16::from_le_bytes([bytes[6], bytes[7]]);
+        let count = read_u32(bytes, 8) as usize;
+        let strings_length = read_u32(bytes, 12) as usize;
+
+        let offsets_end = HEADER_LENGTH + 4 * (count + 1);
+        let strings_end = offsets_end + strings_length;
+        let masks_end = strings_end + if flags & HAS_MASKS != 0 { 4 * count } else { 0 };
+        let signatures_end = masks_end
+            + if flags & HAS_SIGNATURES != 0 {
+                strings_length
+            } else {
+                0
+            };
+        let frequencies_end = signatures_end
+            + if flags & HAS_FREQUENCIES != 0 {
+                4 * count
+            } else {
+                0
+            };
+        if bytes.len() != frequencies_end {
+            return Err(invalid("length does not match header"));
+        }
+
+        let offsets = &bytes[HEADER_LENGTH..offsets_end];
+        let strings = std::str::from_utf8(&bytes[offsets_end..strings_end])
+            .map_err(|_| invalid("words are not UTF-8"))?;
+        let signatures = if flags & HAS_SIGNATURES != 0 {
+            Some(
+                std::str::from_utf8(&bytes[masks_end..signatures_end])
+                    .map_err(|_| invalid("signatures are not UTF-8"))?,
+            )
+        } else {
+            None
+        };
+
+        let mut previous = 0;
+        for i in 0..=count {
+            let offset = read_u32(offsets, 4 * i) as usize;
+            if offset < previous
+                || !strings.is_char_boundary(offset)
+                || signatures.is_some_and(|s| !s.is_char_boundary(offset))
+            {
+                return Err(invalid("offsets out of order"));
+            }
+            previous = offset;
+        }
+        if previous != strings_length {
+            return Err(invalid("offsets do not cover the words"));
+        }
+
+        Ok(Self {
+            count,
+            offsets: HEADER_LENGTH..offsets_end,
+            strings: offsets_end..strings_end,
+            masks: (flags & HAS_MASKS != 0).then_some(strings_end..masks_end),
+            signatures: (flags & HAS_SIGNATURES != 0).then_some(masks_end..signatures_end),
+            frequencies: (flags & HAS_FREQUENCIES != 0).then_some(signatures_end..frequencies_end),
+        })
+    }
+}
+
+/// A read only view of an encoded word list that borrows the bytes it was
+/// parsed from.
+#[derive(Debug, Clone, Copy)]
+pub struct BinaryWordList<'a> {
+    count: usize,
+    offsets: &'a [u8],
+    strings: &'a [u8],
+    masks: Option<&'a [u8]>,
+    signatures: Option<&'a [u8]>,
+    frequencies: Option<&'a [u8]>,
+}
+
+impl<'a> BinaryWordList<'a> {
+    /// Check the header and the layout of the sections without copying them.
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, Error> {
+        Ok(Self::new(bytes, &Sections::check(bytes)?))
+    }
+
+    fn new(bytes: &'a [u8], sections: &Sections) -> Self {
+        Self {
+            count: sections.count,
+            offsets: &bytes[sections.offsets.clone()],
+            strings: &bytes[sections.strings.clone()],
+            masks: sections.masks.clone().map(|r| &bytes[r]),
+            signatures: sections.signatures.clone().map(|r| &bytes[r]),
+            frequencies: sections.frequencies.clone().map(|r| &bytes[r]),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    pub fn has_masks(&self) -> bool {
+        self.masks.is_some()
+    }
+
+    pub fn has_signatures(&self) -> bool {
+        self.signatures.is_some()
+    }
+
+    pub fn has_frequencies(&self) -> bool {
+        self.frequencies.is_some()
+    }
+
+    fn range(&self, index: usize) -> std::ops::Range<usize> {
+        read_u32(self.offsets, 4 * index) as usize..read_u32(self.offsets, 4 * (index + 1)) as usize
+    }
+
+    /// The word at `index`. Each word is checked to be UTF-8 when it is read,
+    /// which cannot fail once the whole table has passed [`Self::parse`].
+    pub fn get(&self, index: usize) -> Option<&'a str> {
+        (index < self.count)
+            .then(|| std::str::from_utf8(&self.strings[self.range(index)]).ok())
+            .flatten()
+    }
+
+    pub fn mask(&self, index: usize) -> Option<u32> {
+        let masks = self.masks?;
+        (index < self.count).then(|| read_u32(masks, 4 * index))
+    }
+
+    pub fn signature(&self, index: usize) -> Option<&'a str> {
+        let signatures = self.signatures?;
+        (index < self.count)
+            .then(|| std::str::from_utf8(&signatures[self.range(index)]).ok())
+            .flatten()
+    }
+
+    pub fn frequency(&self, index: usize) -> Option<u32> {
+        let frequencies = self.frequencies?;
+        (index < self.count).then(|| read_u32(frequencies, 4 * index))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &'a str> + '_ {
+        (0..self.count).filter_map(|i| self.get(i))
+    }
+
+    /// Binary search the sorted words.
+    pub fn contains(&self, word: &str) -> bool {
+        let (mut low, mut high) = (0, self.count);
+        while low < high {
+            let middle = (low + high) / 2;
+            match self.get(middle).unwrap_or_default().cmp(word) {
+                std::cmp::Ordering::Less => low = middle + 1,
+                std::cmp::Ordering::Greater => high = middle,
+                std::cmp::Ordering::Equal => return true,
+            }
+        }
+        false
+    }
+}
+
+/// A binary word list file mapped into memory, checked once when it is
+/// opened.
+#[derive(Debug)]
+pub struct MappedWordList {
+    map: Mmap,
+    sections: Sections,
+}
+
+impl MappedWordList {
+    pub fn open(path: &str) -> Result<Self, Error> {
+        let to_error = |e: std::io::Error| Error::WordListRead(path.to_string(), e.to_string());
+        let file = File::open(path).map_err(to_error)?;
+        // SAFETY: the map is read only, and a word list changed on disk while
+        // it is in use is no worse than one read part way through a change.
+        let map = unsafe { Mmap::map(&file) }.map_err(to_error)?;
+        let sections = Sections::check(&map).map_err(|e| match e {
+            Error::WordListFormat(reason) => Error::WordListFormat(format!("{path}: {reason}")),
+            e => e,
+        })?;
+        Ok(Self { map, sections })
+    }
+
+    /// A view of the words in the map, made without checking them again.
+    pub fn view(&self) -> BinaryWordList<'_> {
+        BinaryWordList::new(&self.map, &self.sections)
+    }
+}
+
+fn read_u32(bytes: &[u8], at: usize) -> u32 {
+    u32::from_le_bytes([bytes[at], bytes[at + 1], bytes[at + 2], bytes[at + 3]])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let bytes = encode_binary(&["pear", "apple", "fig", "pear"], None, true, true);
+        let list = BinaryWordList::parse(&bytes).unwrap();
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.iter().collect::<Vec<&str>>(), ["apple", "fig", "pear"]);
+        assert_eq!(list.mask(1), Some(letter_mask("fig")));
+        assert_eq!(list.signature(2), Some("aepr"));
+        assert!(list.contains("fig"));
+        assert!(!list.contains("plum"));
+        assert_eq!(list.get(3), None);
+    }
+
+    #[test]
+    fn test_optional_sections() {
+        let bytes = encode_binary(&["fig"], None, false, false);
+        let list = BinaryWordList::parse(&bytes).unwrap();
+
+        assert!(!list.has_masks());
+        assert_eq!(list.mask(0), None);
+        assert_eq!(list.signature(0), None);
+        assert_eq!(list.frequency(0), None);
+    }
+
+    #[test]
+    fn test_frequencies_follow_sorted_words() {
+        let bytes = encode_binary(
+            &["pear", "apple", "fig", "pear"],
+            Some(&[5, 9, 2, 1]),
+            false,
+            true,
+        );
+        let list = BinaryWordList::parse(&bytes).unwrap();
+
+        assert!(list.has_frequencies());
+        assert_eq!(list.frequency(0), Some(9));
+        assert_eq!(list.frequency(1), Some(2));
+        assert_eq!(list.frequency(2), Some(6));
+        assert_eq!(list.signature(2), Some("aepr"));
+    }
+
+    #[test]
+    fn test_rejects_damaged_lists() {
+        let bytes = encode_binary(&["apple", "fig"], None, true, false);
+
+        assert_eq!(
+            BinaryWordList::parse(&bytes[..bytes.len() - 1]).err(),
+            Some(Error::WordListFormat(
+                "length does not match header".to_string()
+            ))
+        );
+        assert_eq!(
+            BinaryWordList::parse(b"apple fig").err(),
+            Some(Error::WordListFormat("missing header".to_string()))
+        );
+
+        let mut newer = bytes.clone();
+        newer[4] = 2;
+        assert_eq!(
+            BinaryWordList::parse(&newer).err(),
+            Some(Error::WordListFormat("unsupported version 2".to_string()))
+        );
+    }
+}
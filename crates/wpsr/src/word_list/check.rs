@@ -0,0 +1,157 @@
+use std::collections::HashSet;
+
+use super::{
+    letters::{OTHER, letter_mask},
+    read_words,
+};
+use crate::{Error, WordKind, WordList};
+
+/// The problems found in a list file as it is stored.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ListCheck {
+    source: String,
+    entries: usize,
+    duplicates: Vec<String>,
+    odd_case: Vec<String>,
+    non_alphabetic: Vec<String>,
+    out_of_order: Vec<String>,
+    proper_nouns: Vec<String>,
+}
+
+impl ListCheck {
+    /// Check the words, using the reference list, when there is one, to
+    /// find words that are usually written with a capital letter.
+    pub fn new<'a>(words: impl IntoIterator<Item = &'a str>, reference: Option<&WordList>) -> Self {
+        let proper = reference.map(proper_nouns).unwrap_or_default();
+        let mut check = Self::default();
+        let mut seen = HashSet::new();
+        let mut previous: Option<&str> = None;
+
+        for word in words {
+            check.entries += 1;
+            if !seen.insert(word) {
+                check.duplicates.push(word.to_string());
+            }
+            if WordKind::of(word).is_none() {
+                check.odd_case.push(word.to_string());
+            }
+            if letter_mask(word) & OTHER != 0 {
+                check.non_alphabetic.push(word.to_string());
+            }
+            if previous.is_some_and(|p| p > word) {
+                check.out_of_order.push(word.to_string());
+            }
+            if proper.contains(&word.to_lowercase()) {
+                check.proper_nouns.push(word.to_string());
+            }
+            previous = Some(word);
+        }
+
+        check
+    }
+
+    /// Check the words of a text or binary list file.
+    pub fn load(path: &str, reference: Option<&WordList>) -> Result<Self, Error> {
+        let words = read_words(path)?;
+        let mut check = Self::new(words.iter().map(String::as_str), reference);
+        check.source = path.to_string();
+        Ok(check)
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// The number of words in the file, counting every copy.
+    pub fn entries(&self) -> usize {
+        self.entries
+    }
+
+    /// Copies of words already seen earlier in the file.
+    pub fn duplicates(&self) -> &[String] {
+        &self.duplicates
+    }
+
+    /// Words whose casing shows none of the [`WordKind`]s, such as `iPhone`.
+    pub fn odd_case(&self) -> &[String] {
+        &self.odd_case
+    }
+
+    /// Words with anything other than the letters `a` to `z`.
+    pub fn non_alphabetic(&self) -> &[String] {
+        &self.non_alphabetic
+    }
+
+    /// Words that sort before the word ahead of them.
+    pub fn out_of_order(&self) -> &[String] {
+        &self.out_of_order
+    }
+
+    /// Words the reference list only has with a capital letter.
+    pub fn proper_nouns(&self) -> &[String] {
+        &self.proper_nouns
+    }
+
+    /// Each kind of problem with the words that have it.
+    pub fn problems(&self) -> [(&'static str, &[String]); 5] {
+        [
+            ("Duplicates", &self.duplicates),
+            ("Odd casing", &self.odd_case),
+            ("Non-alphabetic", &self.non_alphabetic),
+            ("Out of order", &self.out_of_order),
+            ("Likely proper nouns", &self.proper_nouns),
+        ]
+    }
+
+    /// The number of words with a problem of any kind, counting a word once
+    /// for each kind.
+    pub fn problem_count(&self) -> usize {
+        self.problems().iter().map(|(_, words)| words.len()).sum()
+    }
+}
+
+/// The lower case forms of the words that the list only has with an upper
+/// case letter.
+pub(crate) fn proper_nouns(reference: &WordList) -> HashSet<String> {
+    let lower = reference
+        .iter()
+        .filter(|w| !w.chars().any(char::is_uppercase))
+        .collect::<HashSet<&str>>();
+    reference
+        .iter()
+        .filter(|w| w.chars().next().is_some_and(char::is_uppercase))
+        .map(|w| w.to_lowercase())
+        .filter(|w| !lower.contains(w.as_str()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_finds_each_problem() {
+        let reference = WordList::from_words(["Abdul", "Abelard", "abandon", "Polish", "polish"]);
+        let check = ListCheck::new(
+            [
+                "abandon", "abandon", "abdul", "Zebra", "aBC", "abelard", "tea-set", "polish",
+            ],
+            Some(&reference),
+        );
+
+        assert_eq!(check.entries(), 8);
+        assert_eq!(check.duplicates(), ["abandon"]);
+        assert_eq!(check.odd_case(), ["aBC"]);
+        assert_eq!(check.non_alphabetic(), ["tea-set"]);
+        assert_eq!(check.out_of_order(), ["Zebra", "polish"]);
+        assert_eq!(check.proper_nouns(), ["abdul", "abelard"]);
+        assert_eq!(check.problem_count(), 7);
+    }
+
+    #[test]
+    fn test_clean_list_without_reference() {
+        let check = ListCheck::new(["abdul", "apple", "fig"], None);
+
+        assert_eq!(check.problem_count(), 0);
+    }
+}
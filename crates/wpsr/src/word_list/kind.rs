@@ -0,0 +1,51 @@
+/// What a word is, as told by how the source list writes it: common words in
+/// lower case, proper nouns capitalised and acronyms in capitals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum WordKind {
+    Common,
+    Proper,
+    Acronym,
+}
+
+impl WordKind {
+    /// The kind of word its casing shows, or `None` for casings such as
+    /// `iPhone` that fit none of them.
+    pub fn of(word: &str) -> Option<Self> {
+        let mut chars = word.chars();
+        let first = chars.next()?;
+        let rest_upper = chars.clone().any(char::is_uppercase);
+        let rest_lower = chars.any(char::is_lowercase);
+
+        if !first.is_uppercase() && !rest_upper {
+            Some(Self::Common)
+        } else if first.is_uppercase() && !rest_upper {
+            if rest_lower || word.chars().count() == 1 {
+                Some(Self::Proper)
+            } else {
+                Some(Self::Acronym)
+            }
+        } else if first.is_uppercase() && !rest_lower {
+            Some(Self::Acronym)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kind_from_casing() {
+        assert_eq!(WordKind::of("abel"), Some(WordKind::Common));
+        assert_eq!(WordKind::of("tea-set"), Some(WordKind::Common));
+        assert_eq!(WordKind::of("Abel"), Some(WordKind::Proper));
+        assert_eq!(WordKind::of("I"), Some(WordKind::Proper));
+        assert_eq!(WordKind::of("ABC"), Some(WordKind::Acronym));
+        assert_eq!(WordKind::of("A-1"), Some(WordKind::Acronym));
+        assert_eq!(WordKind::of("McDonald"), None);
+        assert_eq!(WordKind::of("iPhone"), None);
+        assert_eq!(WordKind::of(""), None);
+    }
+}
@@ -0,0 +1,56 @@
+/// Bits `0` to `25` of a letter mask, one for each letter `a` to `z`.
+pub const LETTERS: u32 = (1 << 26) - 1;
+/// Set in a letter mask when the word has any character other than `a` to
+/// `z` in either case.
+pub const OTHER: u32 = 1 << 31;
+
+/// The counts of each letter `a` to `z` in a word.
+pub type LetterCounts = [u8; 26];
+
+fn letter_index(c: char) -> Option<usize> {
+    c.is_ascii_alphabetic()
+        .then(|| (c.to_ascii_lowercase() as u8 - b'a') as usize)
+}
+
+/// Bit `n` is set when the word contains the `n`th letter of the alphabet,
+/// in either case, and [`OTHER`] when it contains anything else.
+pub fn letter_mask(word: &str) -> u32 {
+    word.chars().fold(0, |mask, c| match letter_index(c) {
+        Some(i) => mask | 1 << i,
+        None => mask | OTHER,
+    })
+}
+
+/// How many times each letter appears in the word, ignoring case and
+/// anything that is not a letter.
+pub fn letter_counts(word: &str) -> LetterCounts {
+    let mut counts = [0u8; 26];
+    for i in word.chars().filter_map(letter_index) {
+        counts[i] = counts[i].saturating_add(1);
+    }
+    counts
+}
+
+/// The letters of the word in sorted order, shared by all its anagrams.
+pub fn signature(word: &str) -> String {
+    let mut letters = word.chars().collect::<Vec<char>>();
+    letters.sort_unstable();
+    letters.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_masks_and_counts() {
+        assert_eq!(letter_mask("abz"), 1 | 2 | 1 << 25);
+        assert_eq!(letter_mask("Abz"), letter_mask("abz"));
+        assert_eq!(letter_mask("a-z"), 1 | 1 << 25 | OTHER);
+        assert_eq!(letter_mask("a-z") & LETTERS, 1 | 1 << 25);
+
+        let counts = letter_counts("Banana");
+        assert_eq!((counts[0], counts[1], counts[13]), (3, 1, 2));
+        assert_eq!(counts.iter().map(|c| *c as usize).sum::<usize>(), 6);
+    }
+}
@@ -0,0 +1,170 @@
+use std::collections::{BTreeMap, HashSet};
+
+use colorful::Colorful;
+
+use super::{
+    letters::{OTHER, letter_counts, letter_mask},
+    read_words,
+};
+use crate::Error;
+
+const BAR_WIDTH: usize = 40;
+
+/// Counts describing the words of a list file as they are stored, before
+/// the duplicates are dropped on loading.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ListStats {
+    source: String,
+    entries: usize,
+    duplicates: usize,
+    lengths: BTreeMap<usize, usize>,
+    letters: [usize; 26],
+    repeated_letters: usize,
+    non_alphabetic: usize,
+}
+
+impl ListStats {
+    pub fn from_words<'a>(words: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut stats = Self::default();
+        let mut seen = HashSet::new();
+
+        for word in words {
+            stats.entries += 1;
+            if !seen.insert(word) {
+                stats.duplicates += 1;
+            }
+            *stats.lengths.entry(word.chars().count()).or_default() += 1;
+            for (total, count) in stats.letters.iter_mut().zip(letter_counts(word)) {
+                *total += count as usize;
+            }
+            if has_repeated_letter(word) {
+                stats.repeated_letters += 1;
+            }
+            if letter_mask(word) & OTHER != 0 {
+                stats.non_alphabetic += 1;
+            }
+        }
+
+        stats
+    }
+
+    /// Read the entries of a text or binary list file.
+    pub fn load(path: &str) -> Result<Self, Error> {
+        let words = read_words(path)?;
+        let mut stats = Self::from_words(words.iter().map(String::as_str));
+        stats.source = path.to_string();
+        Ok(stats)
+    }
+
+    /// The number of words in the file, counting every copy.
+    pub fn entries(&self) -> usize {
+        self.entries
+    }
+
+    /// Copies of words already seen earlier in the file.
+    pub fn duplicates(&self) -> usize {
+        self.duplicates
+    }
+
+    /// The number of words of each length.
+    pub fn lengths(&self) -> &BTreeMap<usize, usize> {
+        &self.lengths
+    }
+
+    /// How many times each letter `a` to `z` is used, in either case.
+    pub fn letters(&self) -> &[usize; 26] {
+        &self.letters
+    }
+
+    /// Words with the same letter twice in a row, which `boxed prepare`
+    /// drops.
+    pub fn repeated_letters(&self) -> usize {
+        self.repeated_letters
+    }
+
+    /// Words with anything other than the letters `a` to `z`.
+    pub fn non_alphabetic(&self) -> usize {
+        self.non_alphabetic
+    }
+
+    pub fn report_string(&self) -> String {
+        let mut s = String::new();
+        s.push_str(&format!(
+            "    {:<22}{}\n",
+            "Words",
+            format!("{:>8}", self.entries).bold()
+        ));
+        for (label, count) in [
+            ("Duplicates", self.duplicates),
+            ("Repeated letters", self.repeated_letters),
+            ("Non-alphabetic", self.non_alphabetic),
+        ] {
+            s.push_str(&format!(
+                "    {label:<22}{count:>8}  {:5.1}%\n",
+                percent(count, self.entries)
+            ));
+        }
+
+        s.push_str(&format!("\n    {}\n", "Lengths".underlined()));
+        let most = self.lengths.values().copied().max().unwrap_or_default();
+        for (length, count) in self.lengths.iter() {
+            let bar = "#".repeat((count * BAR_WIDTH).div_ceil(most.max(1)));
+            s.push_str(&format!("    {length:>4}  {count:>8}  {bar}\n"));
+        }
+
+        s.push_str(&format!("\n    {}\n", "Letters".underlined()));
+        let total = self.letters.iter().sum::<usize>();
+        let mut letters = ('a'..='z').zip(self.letters).collect::<Vec<_>>();
+        letters.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        for row in letters.chunks(6) {
+            let cells = row
+                .iter()
+                .map(|(letter, count)| format!("{letter} {:5.1}%", percent(*count, total)))
+                .collect::<Vec<String>>();
+            s.push_str(&format!("    {}\n", cells.join("   ")));
+        }
+
+        s
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+}
+
+fn has_repeated_letter(word: &str) -> bool {
+    let lower = word.to_lowercase();
+    lower
+        .chars()
+        .zip(lower.chars().skip(1))
+        .any(|(a, b)| a == b)
+}
+
+fn percent(count: usize, total: usize) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        100.0 * count as f64 / total as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counts_every_entry() {
+        let stats = ListStats::from_words(["abandon", "abandon", "Apple", "tea-set", "fig"]);
+
+        assert_eq!(stats.entries(), 5);
+        assert_eq!(stats.duplicates(), 1);
+        assert_eq!(stats.repeated_letters(), 1);
+        assert_eq!(stats.non_alphabetic(), 1);
+        assert_eq!(
+            stats.lengths().iter().collect::<Vec<_>>(),
+            [(&3, &1), (&5, &1), (&7, &3)]
+        );
+        assert_eq!(stats.letters()[0], 6);
+        assert_eq!(stats.letters()[25], 0);
+    }
+}
@@ -0,0 +1,443 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    fmt::Display,
+};
+
+use colorful::Colorful;
+
+use crate::{Error, WordList};
+
+const DEFAULT_WORD_LENGTH: usize = 5;
+const SCORING_BUDGET: usize = 4_000_000;
+
+/// Colour of a tile after a guess.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Mark {
+    /// Right letter in the right place
+    Green,
+    /// Letter in the word but in another place
+    Yellow,
+    /// Letter not in the word, or not as many times as guessed
+    Grey,
+}
+
+impl Mark {
+    fn parse(c: char) -> Option<Self> {
+        match c.to_ascii_lowercase() {
+            'g' => Some(Self::Green),
+            'y' => Some(Self::Yellow),
+            'b' | '.' | '-' => Some(Self::Grey),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Mark {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Mark::Green => write!(f, "g"),
+            Mark::Yellow => write!(f, "y"),
+            Mark::Grey => write!(f, "b"),
+        }
+    }
+}
+
+/// The marks a guess earns against the answer. Greens are placed first, then
+/// yellows while the answer still has unmatched copies of the letter.
+pub fn feedback(guess: &str, answer: &str) -> Vec<Mark> {
+    let guess = guess.as_bytes();
+    let answer = answer.as_bytes();
+    let mut marks = vec![Mark::Grey; guess.len()];
+    let mut unmatched = [0u8; 26];
+
+    for i in 0..guess.len() {
+        if guess[i] == answer[i] {
+            marks[i] = Mark::Green;
+        } else if answer[i].is_ascii_lowercase() {
+            unmatched[(answer[i] - b'a') as usize] += 1;
+        }
+    }
+    for i in 0..guess.len() {
+        if marks[i] != Mark::Green && guess[i].is_ascii_lowercase() {
+            let count = &mut unmatched[(guess[i] - b'a') as usize];
+            if *count > 0 {
+                *count -= 1;
+                marks[i] = Mark::Yellow;
+            }
+        }
+    }
+    marks
+}
+
+/// Marks packed into a single number for counting outcomes.
+fn pattern_code(marks: &[Mark]) -> u32 {
+    marks.iter().fold(0, |code, mark| {
+        code * 3
+            + match mark {
+                Mark::Grey => 0,
+                Mark::Yellow => 1,
+                Mark::Green => 2,
+            }
+    })
+}
+
+/// One of the boards being played, with the marks it showed for each guess.
+#[derive(Debug, Clone, Default)]
+pub struct WordleBoard {
+    history: Vec<(String, Vec<Mark>)>,
+    candidates: Vec<String>,
+}
+
+impl WordleBoard {
+    /// The answer once a guess has turned every tile green.
+    pub fn solved(&self) -> Option<&str> {
+        self.history
+            .iter()
+            .find(|(_, marks)| marks.iter().all(|m| *m == Mark::Green))
+            .map(|(guess, _)| guess.as_str())
+    }
+
+    pub fn candidates(&self) -> &[String] {
+        &self.candidates
+    }
+
+    fn filter(&mut self, words: &[String]) {
+        self.candidates = words
+            .iter()
+            .filter(|word| {
+                self.history
+                    .iter()
+                    .all(|(guess, marks)| feedback(guess, word) == *marks)
+            })
+            .cloned()
+            .collect();
+    }
+
+    /// Expected information in bits from the guess, measured over the
+    /// spread of outcomes it gives across the sampled candidates.
+    fn information(&self, guess: &str, sample: &[&String]) -> f64 {
+        let mut outcomes: HashMap<u32, usize> = HashMap::new();
+        for candidate in sample.iter() {
+            *outcomes
+                .entry(pattern_code(&feedback(guess, candidate)))
+                .or_default() += 1;
+        }
+        let total = sample.len() as f64;
+        outcomes
+            .values()
+            .map(|count| {
+                let p = *count as f64 / total;
+                -p * p.log2()
+            })
+            .sum()
+    }
+}
+
+#[derive(Debug)]
+pub struct Wordle<'a> {
+    length: usize,
+    guesses: Vec<String>,
+    boards: Vec<WordleBoard>,
+    words: &'a WordList,
+    max: usize,
+    suggestions: Vec<(String, f64)>,
+}
+
+impl<'a> Wordle<'a> {
+    /// Set up the boards from the shared guesses and the marks each board
+    /// showed, given for each board as one string of `g`, `y` and `b` per
+    /// guess, separated by commas. A board can stop once it has been solved.
+    pub fn new(
+        guesses: &[String],
+        feedback: &[String],
+        boards: usize,
+        words: &'a WordList,
+    ) -> Result<Self, Error> {
+        let guesses = guesses
+            .iter()
+            .map(|g| g.to_lowercase())
+            .collect::<Vec<String>>();
+        let length = guesses.first().map_or(DEFAULT_WORD_LENGTH, |g| g.len());
+        if let Some(guess) = guesses
+            .iter()
+            .find(|g| g.len() != length || !g.chars().all(|c| c.is_ascii_lowercase()))
+        {
+            return Err(Error::WordleGuess(guess.clone()));
+        }
+
+        let count = boards.max(feedback.len()).max(1);
+        let mut wordle_boards = Vec::new();
+        for index in 0..count {
+            let board_feedback = feedback.get(index).map_or("", |f| f.as_str());
+            let rows = board_feedback
+                .split(',')
+                .filter(|r| !r.is_empty())
+                .collect::<Vec<&str>>();
+
+            let mut board = WordleBoard::default();
+            for (guess, row) in guesses.iter().zip(rows.iter()) {
+                if board.solved().is_some() {
+                    break;
+                }
+                let marks = row.chars().map(Mark::parse).collect::<Option<Vec<Mark>>>();
+                match marks {
+                    Some(marks) if marks.len() == length => {
+                        board.history.push((guess.clone(), marks));
+                    }
+                    _ => return Err(Error::WordleFeedback(row.to_string())),
+                }
+            }
+            if rows.len() > guesses.len()
+                || (board.solved().is_none() && rows.len() != guesses.len())
+            {
+                return Err(Error::WordleFeedback(board_feedback.to_string()));
+            }
+            wordle_boards.push(board);
+        }
+
+        Ok(Self {
+            words,
+            length,
+            guesses,
+            boards: wordle_boards,
+            max: 10,
+            suggestions: Vec::new(),
+        })
+    }
+
+    pub fn set_max_solutions(&mut self, value: usize) -> &mut Self {
+        self.max = value;
+        self
+    }
+
+    pub fn boards(&self) -> &[WordleBoard] {
+        &self.boards
+    }
+
+    pub fn suggestions(&self) -> &[(String, f64)] {
+        &self.suggestions
+    }
+
+    /// Filter the candidates of each board by its own marks, then rank the
+    /// guesses by the total expected information across the unsolved boards.
+    #[tracing::instrument(skip(self))]
+    pub fn find_solutions(&mut self) -> Result<&mut Self, Error> {
+        let mut words = self
+            .words
+            .iter()
+            .map(|w| w.to_lowercase())
+            .filter(|w| w.len() == self.length && w.chars().all(|c| c.is_ascii_lowercase()))
+            .collect::<Vec<String>>();
+        words.sort();
+        words.dedup();
+
+        for board in self.boards.iter_mut() {
+            board.filter(&words);
+            tracing::debug!("Board has {} candidates", board.candidates.len());
+        }
+
+        let open = self
+            .boards
+            .iter()
+            .filter(|b| b.solved().is_none())
+            .collect::<Vec<&WordleBoard>>();
+        if let Some(board) = open.iter().find(|b| b.candidates.is_empty()) {
+            let history = board
+                .history
+                .iter()
+                .map(|(_, marks)| marks.iter().map(|m| m.to_string()).collect::<String>())
+                .collect::<Vec<String>>()
+                .join(",");
+            return Err(Error::WordleNoCandidates(history));
+        }
+        if open.is_empty() {
+            self.suggestions.clear();
+            return Ok(self);
+        }
+
+        // Score every word when that is affordable, otherwise only the words
+        // that could still be an answer, measured over an even sample of the
+        // candidates on each board.
+        let total = open.iter().map(|b| b.candidates.len()).sum::<usize>();
+        let pool = if words.len() * total <= SCORING_BUDGET {
+            words.clone()
+        } else {
+            open.iter()
+                .flat_map(|b| b.candidates.iter().cloned())
+                .collect::<BTreeSet<String>>()
+                .into_iter()
+                .collect()
+        };
+        let per_board = (SCORING_BUDGET / pool.len() / open.len()).max(1);
+        let samples = open
+            .iter()
+            .map(|b| {
+                let step = b.candidates.len().div_ceil(per_board).max(1);
+                b.candidates.iter().step_by(step).collect::<Vec<&String>>()
+            })
+            .collect::<Vec<Vec<&String>>>();
+        tracing::debug!("Scoring {} guesses", pool.len());
+
+        let mut suggestions = pool
+            .iter()
+            .map(|guess| {
+                let mut score = open
+                    .iter()
+                    .zip(samples.iter())
+                    .map(|(board, sample)| board.information(guess, sample))
+                    .sum::<f64>();
+                // Prefer a guess that might also be an answer when the
+                // information is otherwise equal.
+                if open
+                    .iter()
+                    .any(|b| b.candidates.binary_search(guess).is_ok())
+                {
+                    score += 1e-6;
+                }
+                (guess.clone(), score)
+            })
+            .collect::<Vec<(String, f64)>>();
+        suggestions.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        suggestions.truncate(self.max);
+
+        self.suggestions = suggestions;
+
+        Ok(self)
+    }
+
+    pub fn word_source_string(&self) -> String {
+        self.words.source_string()
+    }
+
+    pub fn solutions_title(&self) -> String {
+        let intro = format!("{} Wordle boards after ", self.boards.len());
+        let guesses = if self.guesses.is_empty() {
+            "no guesses".to_string()
+        } else {
+            self.guesses.join(", ")
+        };
+        let mut ul = String::new();
+        for _ in 0..(intro.len() + guesses.len()) {
+            ul.push('‾');
+        }
+
+        let summary = format!("{}{}", intro.yellow().bold(), guesses.blue().bold());
+        format!("{}\n{}", summary, ul.bold().yellow())
+    }
+
+    pub fn boards_string(&self) -> String {
+        let mut s = String::new();
+
+        for (index, board) in self.boards.iter().enumerate() {
+            match board.solved() {
+                Some(answer) => {
+                    s.push_str(&format!("  Board {}: solved with {answer}\n", index + 1));
+                }
+                None => {
+                    s.push_str(&format!(
+                        "  Board {}: {} candidates\n",
+                        index + 1,
+                        board.candidates.len()
+                    ));
+                    let shown = board
+                        .candidates
+                        .iter()
+                        .take(self.max)
+                        .cloned()
+                        .collect::<Vec<String>>();
+                    let more = board.candidates.len().saturating_sub(self.max);
+                    if more > 0 {
+                        s.push_str(&format!("    {} and {more} more\n", shown.join(" ")));
+                    } else {
+                        s.push_str(&format!("    {}\n", shown.join(" ")));
+                    }
+                }
+            }
+        }
+        s
+    }
+
+    pub fn suggestions_string(&self) -> String {
+        let mut s = String::new();
+
+        if self.suggestions.is_empty() {
+            s.push_str("  All boards solved.\n");
+            return s;
+        }
+
+        s.push_str("  ");
+        s.push_str(&"Suggested guesses".underlined().yellow().to_string());
+        s.push_str("\n\n");
+        for (guess, bits) in self.suggestions.iter() {
+            s.push_str(&format!("    {guess}  {bits:.2} bits\n"));
+        }
+        s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    fn marks(marks: &str) -> Vec<Mark> {
+        marks.chars().map(|c| Mark::parse(c).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_feedback_with_repeated_letters() {
+        assert_eq!(feedback("crane", "crane"), marks("ggggg"));
+        assert_eq!(feedback("speed", "abide"), marks("bbyby"));
+        assert_eq!(feedback("eerie", "three"), marks("ybgbg"));
+        assert_eq!(feedback("geese", "eerie"), marks("bgybg"));
+    }
+
+    #[test]
+    fn test_boards_filter_independently() {
+        let words = WordList::from_words([
+            "crane", "slate", "plate", "crate", "grate", "shine", "stone",
+        ]);
+        let mut wordle = Wordle::new(
+            &strings(&["crate"]),
+            &strings(&["bbggg", "ggggg", "bbbbg"]),
+            0,
+            &words,
+        )
+        .unwrap();
+        wordle.find_solutions().unwrap();
+
+        let boards = wordle.boards();
+        assert_eq!(boards[0].candidates(), vec!["plate", "slate"]);
+        assert_eq!(boards[1].solved(), Some("crate"));
+        assert_eq!(boards[2].candidates(), vec!["shine"]);
+    }
+
+    #[test]
+    fn test_suggestion_splits_unsolved_boards() {
+        let words = WordList::from_words(["baker", "maker", "taker", "fakir", "btmxz"]);
+        let mut wordle = Wordle::new(&[], &[], 2, &words).unwrap();
+        wordle.find_solutions().unwrap();
+
+        // Guessing a word with b, m and t tells apart the words that differ
+        // only in their first letter.
+        assert_eq!(wordle.suggestions()[0].0, "btmxz");
+    }
+
+    #[test]
+    fn test_invalid_feedback() {
+        let guesses = strings(&["crane", "slate"]);
+        let words = WordList::default();
+        assert_eq!(
+            Wordle::new(&guesses, &strings(&["bbbbb"]), 1, &words).err(),
+            Some(Error::WordleFeedback("bbbbb".to_string()))
+        );
+        assert_eq!(
+            Wordle::new(&guesses, &strings(&["bbxbb,bbbbb"]), 1, &words).err(),
+            Some(Error::WordleFeedback("bbxbb".to_string()))
+        );
+        assert!(Wordle::new(&guesses, &strings(&["ggggg"]), 1, &words).is_ok());
+    }
+}
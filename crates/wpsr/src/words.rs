@@ -1,24 +1,20 @@
-use std::collections::HashMap;
-
 use colorful::Colorful;
 
-use crate::{DEFAULT_SOURCE_DIR, DEFAULT_WORDS_SOURCE_FILE, Error, WordFilters};
+use crate::{Distribution, Error, WordList};
 
-#[derive(Debug, Default)]
-pub struct Words {
-    settings: HashMap<String, String>,
+#[derive(Debug)]
+pub struct Words<'a> {
     letters: Vec<char>,
-    word_source: String,
-    words: Vec<String>,
+    words: &'a WordList,
     solutions: Vec<String>,
     max: usize,
     required: Option<String>,
     pangram: bool,
-    distribution: HashMap<usize, i32>,
+    distribution: Distribution,
 }
 
-impl Words {
-    pub fn new(letters: &str, settings: HashMap<String, String>) -> Result<Self, Error> {
+impl<'a> Words<'a> {
+    pub fn new(letters: &str, words: &'a WordList) -> Result<Self, Error> {
         if letters.len() < 3 || letters.len() > 26 {
             return Err(Error::TooFewOrManyLetters(letters.len()));
         }
@@ -29,61 +25,16 @@ impl Words {
             .collect::<Vec<char>>();
 
         Ok(Self {
-            settings,
+            words,
             letters,
-            ..Default::default()
+            solutions: Vec::new(),
+            max: 0,
+            required: None,
+            pangram: false,
+            distribution: Distribution::new(),
         })
     }
 
-    pub fn set_word_source(&mut self, dir: Option<String>, file: Option<String>) -> &mut Self {
-        // Setup settings
-        let mut src_directory = self
-            .settings
-            .get("source_dir")
-            .map_or(DEFAULT_SOURCE_DIR, |v| v)
-            .to_string();
-        let mut src_file = self
-            .settings
-            .get("source_words_file")
-            .map_or(DEFAULT_WORDS_SOURCE_FILE, |v| v)
-            .to_string();
-
-        if let Some(sd) = dir {
-            src_directory = sd;
-        };
-        if let Some(sf) = file {
-            src_file = sf;
-        };
-
-        let src = format!("{}/{}", src_directory.clone(), src_file.clone());
-        println!("Using word list: {src}");
-        tracing::info!("Using word list: {}", src);
-
-        self.word_source = src;
-
-        self
-    }
-
-    pub fn load_words(&mut self) -> &mut Self {
-        let mut words = Vec::new();
-
-        for line in std::fs::read_to_string(&self.word_source)
-            .expect("Failed to read words file")
-            .lines()
-        {
-            if !line.is_empty() {
-                let ws = line.split_whitespace();
-                for w in ws {
-                    words.push(w.to_string());
-                }
-            }
-        }
-
-        self.words = words;
-
-        self
-    }
-
     pub fn set_max_solutions(&mut self, value: usize) -> &mut Self {
         self.max = value;
         self
@@ -108,17 +59,16 @@ impl Words {
             .filter(|&c| !self.letters.contains(&c))
             .collect::<String>();
 
-        let words = self.words.clone();
-        println!("{} words found", words.len());
-        let mut filtered = words.filter_excludes_letters(&excluded_letters);
+        tracing::debug!("{} words found", self.words.len());
+        let mut filtered = self.words.excluding_letters(&excluded_letters);
         if let Some(required) = &self.required {
-            filtered = filtered.filter_includes_any_letters(required);
+            filtered = filtered.including_any_letters(required);
         }
         if self.pangram {
-            filtered =
-                filtered.filter_includes_all_letters(&self.letters.iter().collect::<String>());
+            filtered = filtered.including_all_letters(&self.letters.iter().collect::<String>());
         }
-        println!("{} words found", filtered.len());
+        tracing::debug!("{} words found", filtered.len());
+        let mut filtered = filtered.to_vec();
 
         filtered.sort_by(|a, b| {
             let a_len = a.len();
@@ -141,28 +91,21 @@ impl Words {
     }
 
     pub fn count_solution(&mut self, chain_length: usize) -> &mut Self {
-        if let Some(count) = self.distribution.get(&chain_length) {
-            let v = count + 1;
-            self.distribution.insert(chain_length, v);
-        } else {
-            self.distribution.insert(chain_length, 1);
-        }
-
+        self.distribution.count(chain_length);
         self
     }
 
+    pub fn distribution(&self) -> &Distribution {
+        &self.distribution
+    }
+
     pub fn word_source_string(&self) -> String {
-        let s1 = "Using words sourced from ".light_cyan().dim().to_string();
-        let s2 = self.word_source.clone().light_cyan().bold().to_string();
-        format!("{s1}{s2}")
+        self.words.source_string()
     }
 
     pub fn distribution_string(&self) -> String {
         let mut s = String::new();
-        let mut distributions = self.distribution.iter().collect::<Vec<_>>();
-        distributions.sort_by_key(|a| a.0);
-
-        for d in distributions {
+        for d in self.distribution.lengths() {
             s.push_str(&format!(
                 "  - {:3.0} solutions with {:2.0} words\n",
                 d.1, d.0
@@ -201,7 +144,7 @@ impl Words {
         s.push_str(
             &format!(
                 "{} Solutions with {} words.",
-                self.distribution.get(&word_length).unwrap_or(&0),
+                self.distribution.get(word_length),
                 word_length
             )
             .underlined()
@@ -217,7 +160,7 @@ impl Words {
                 s.push_str(
                     &format!(
                         "{} Solutions with {} words.",
-                        self.distribution.get(&word_length).unwrap_or(&0),
+                        self.distribution.get(word_length),
                         word_length
                     )
                     .underlined()
@@ -0,0 +1,419 @@
+use std::{collections::HashSet, fmt::Display};
+
+use colorful::Colorful;
+
+use crate::Error;
+
+pub use generator::{Directions, PuzzleGenerator};
+
+mod generator;
+
+/// The eight directions a word can run in the grid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+    Up,
+    UpRight,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 8] = [
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+        Direction::Up,
+        Direction::UpRight,
+    ];
+
+    /// Change in row and column for each step in the direction.
+    pub fn delta(&self) -> (isize, isize) {
+        match self {
+            Direction::Right => (0, 1),
+            Direction::DownRight => (1, 1),
+            Direction::Down => (1, 0),
+            Direction::DownLeft => (1, -1),
+            Direction::Left => (0, -1),
+            Direction::UpLeft => (-1, -1),
+            Direction::Up => (-1, 0),
+            Direction::UpRight => (-1, 1),
+        }
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Direction::Right => write!(f, "right"),
+            Direction::DownRight => write!(f, "down and right"),
+            Direction::Down => write!(f, "down"),
+            Direction::DownLeft => write!(f, "down and left"),
+            Direction::Left => write!(f, "left"),
+            Direction::UpLeft => write!(f, "up and left"),
+            Direction::Up => write!(f, "up"),
+            Direction::UpRight => write!(f, "up and right"),
+        }
+    }
+}
+
+/// Where a word was placed or found, with the row and column of its first
+/// letter counted from zero.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Placement {
+    pub word: String,
+    pub row: usize,
+    pub column: usize,
+    pub direction: Direction,
+}
+
+impl Display for Placement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} at row {}, column {}, reading {}",
+            self.word,
+            self.row + 1,
+            self.column + 1,
+            self.direction
+        )
+    }
+}
+
+/// A rectangular grid of letters.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Grid {
+    rows: usize,
+    columns: usize,
+    cells: Vec<Option<char>>,
+}
+
+impl Grid {
+    /// An empty grid ready to have words placed in it.
+    pub fn new(rows: usize, columns: usize) -> Self {
+        Self {
+            rows,
+            columns,
+            cells: vec![None; rows * columns],
+        }
+    }
+
+    /// Parse a grid given as rows separated by whitespace, `/` or `,`, for
+    /// example `cat/oxo/wed`.
+    pub fn parse(grid: &str) -> Result<Self, Error> {
+        let rows = grid
+            .split(|c: char| c.is_whitespace() || c == '/' || c == ',')
+            .filter(|r| !r.is_empty())
+            .map(|r| r.to_ascii_lowercase())
+            .collect::<Vec<String>>();
+
+        let columns = rows.first().map_or(0, |r| r.chars().count());
+        if columns == 0
+            || rows.iter().any(|r| r.chars().count() != columns)
+            || rows
+                .iter()
+                .any(|r| !r.chars().all(|c| c.is_ascii_lowercase()))
+        {
+            return Err(Error::WordSearchGrid(grid.to_string()));
+        }
+
+        Ok(Self {
+            rows: rows.len(),
+            columns,
+            cells: rows.iter().flat_map(|r| r.chars()).map(Some).collect(),
+        })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Cells covered by a word of the given length starting at the cell, or
+    /// `None` if the word would run off the grid.
+    pub fn cells_for(
+        &self,
+        length: usize,
+        row: usize,
+        column: usize,
+        direction: Direction,
+    ) -> Option<Vec<usize>> {
+        let (dr, dc) = direction.delta();
+        (0..length as isize)
+            .map(|step| {
+                let r = row as isize + dr * step;
+                let c = column as isize + dc * step;
+                (r >= 0 && c >= 0 && (r as usize) < self.rows && (c as usize) < self.columns)
+                    .then(|| r as usize * self.columns + c as usize)
+            })
+            .collect()
+    }
+
+    /// Find the first place the word can be read in any direction.
+    pub fn find(&self, word: &str) -> Option<Placement> {
+        let letters = word.chars().collect::<Vec<char>>();
+        for cell in 0..self.cells.len() {
+            let (row, column) = (cell / self.columns, cell % self.columns);
+            for direction in Direction::ALL {
+                let Some(cells) = self.cells_for(letters.len(), row, column, direction) else {
+                    continue;
+                };
+                if cells
+                    .iter()
+                    .zip(letters.iter())
+                    .all(|(c, l)| self.cells[*c] == Some(*l))
+                {
+                    return Some(Placement {
+                        word: word.to_string(),
+                        row,
+                        column,
+                        direction,
+                    });
+                }
+            }
+        }
+        None
+    }
+
+    /// The rows of the grid in the form accepted by `wordsearch solve`.
+    pub fn rows_string(&self) -> String {
+        self.cells
+            .chunks(self.columns)
+            .map(|row| row.iter().map(|c| c.unwrap_or('.')).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("/")
+    }
+
+    /// The grid as spaced capital letters, showing only the cells in the
+    /// mask when one is given.
+    pub fn grid_string(&self, mask: Option<&HashSet<usize>>) -> String {
+        let mut s = String::new();
+        for (r, row) in self.cells.chunks(self.columns).enumerate() {
+            let row = row
+                .iter()
+                .enumerate()
+                .map(|(c, letter)| {
+                    let shown = mask.is_none_or(|m| m.contains(&(r * self.columns + c)));
+                    match letter {
+                        Some(l) if shown => l.to_ascii_uppercase(),
+                        _ => '.',
+                    }
+                })
+                .map(|l| l.to_string())
+                .collect::<Vec<String>>()
+                .join(" ");
+            s.push_str(&format!("    {row}\n"));
+        }
+        s
+    }
+
+    /// The grid showing only the letters of the placed words.
+    pub fn answer_key(&self, placements: &[Placement]) -> String {
+        let mask = placements
+            .iter()
+            .filter_map(|p| self.cells_for(p.word.len(), p.row, p.column, p.direction))
+            .flatten()
+            .collect::<HashSet<usize>>();
+        self.grid_string(Some(&mask))
+    }
+
+    fn get(&self, cell: usize) -> Option<char> {
+        self.cells[cell]
+    }
+
+    fn set(&mut self, cell: usize, letter: char) {
+        self.cells[cell] = Some(letter);
+    }
+}
+
+/// Read a theme file of words separated by whitespace or new lines.
+pub(crate) fn read_theme(path: &str) -> Result<Vec<String>, Error> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| Error::WordListRead(path.to_string(), e.to_string()))?;
+    Ok(text.split_whitespace().map(|w| w.to_lowercase()).collect())
+}
+
+#[derive(Debug, Default)]
+pub struct WordSearch {
+    grid: Grid,
+    targets: Vec<String>,
+    found: Vec<Placement>,
+    missing: Vec<String>,
+}
+
+impl WordSearch {
+    pub fn new(grid: &str) -> Result<Self, Error> {
+        Ok(Self {
+            grid: Grid::parse(grid)?,
+            ..Default::default()
+        })
+    }
+
+    pub fn set_targets(&mut self, words: Vec<String>) -> &mut Self {
+        self.targets.extend(words.iter().map(|w| w.to_lowercase()));
+        self
+    }
+
+    pub fn load_theme(&mut self, theme: Option<String>) -> Result<&mut Self, Error> {
+        if let Some(path) = theme {
+            tracing::info!("Using theme: {}", path);
+            self.targets.extend(read_theme(&path)?);
+        }
+        Ok(self)
+    }
+
+    /// Look for every target word in all eight directions.
+    #[tracing::instrument(skip(self))]
+    pub fn find_solutions(&mut self) -> Result<&mut Self, Error> {
+        if self.targets.is_empty() {
+            return Err(Error::NoWordList);
+        }
+
+        self.found.clear();
+        self.missing.clear();
+        for word in self.targets.iter() {
+            match self.grid.find(word) {
+                Some(placement) => self.found.push(placement),
+                None => self.missing.push(word.clone()),
+            }
+        }
+        tracing::debug!("{} found, {} missing", self.found.len(), self.missing.len());
+
+        Ok(self)
+    }
+
+    pub fn found(&self) -> &[Placement] {
+        &self.found
+    }
+
+    pub fn missing(&self) -> &[String] {
+        &self.missing
+    }
+
+    pub fn solutions_title(&self) -> String {
+        let intro = "Words in the grid";
+        let mut ul = String::new();
+        for _ in 0..intro.len() {
+            ul.push('‾');
+        }
+
+        format!(
+            "{}\n{}\n\n{}",
+            intro.yellow().bold(),
+            ul.bold().yellow(),
+            self.grid.grid_string(None).blue().bold()
+        )
+    }
+
+    pub fn solutions_string(&self) -> String {
+        let mut s = String::new();
+
+        s.push_str("  ");
+        s.push_str(
+            &format!(
+                "{} of {} words found.",
+                self.found.len(),
+                self.targets.len()
+            )
+            .underlined()
+            .yellow()
+            .to_string(),
+        );
+        s.push_str("\n\n");
+
+        for placement in self.found.iter() {
+            s.push_str(&format!("    {placement}\n"));
+        }
+        for word in self.missing.iter() {
+            s.push_str(&format!("    {word} not found\n"));
+        }
+
+        s.push_str("\n  ");
+        s.push_str(&"Answer key".underlined().yellow().to_string());
+        s.push_str("\n\n");
+        s.push_str(&self.grid.answer_key(&self.found));
+        s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_grid() {
+        let grid = Grid::parse("cat/oxo").unwrap();
+        assert_eq!(grid.rows(), 2);
+        assert_eq!(grid.columns(), 3);
+        assert_eq!(grid.rows_string(), "cat/oxo");
+
+        assert_eq!(
+            Grid::parse("cat/ox"),
+            Err(Error::WordSearchGrid("cat/ox".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_find_words_in_every_direction() {
+        // c a t
+        // o x o
+        // w e d
+        let grid = Grid::parse("cat/oxo/wed").unwrap();
+
+        let found = |word: &str| grid.find(word).map(|p| (p.row, p.column, p.direction));
+        assert_eq!(found("cat"), Some((0, 0, Direction::Right)));
+        assert_eq!(found("cow"), Some((0, 0, Direction::Down)));
+        assert_eq!(found("dew"), Some((2, 2, Direction::Left)));
+        assert_eq!(found("cxd"), Some((0, 0, Direction::DownRight)));
+        assert_eq!(found("wxt"), Some((2, 0, Direction::UpRight)));
+        assert_eq!(found("dog"), None);
+    }
+
+    #[test]
+    fn test_solve_reports_missing_words() {
+        let mut search = WordSearch::new("cat/oxo/wed").unwrap();
+        search
+            .set_targets(vec!["TOD".to_string(), "dog".to_string()])
+            .find_solutions()
+            .unwrap();
+
+        assert_eq!(
+            search.found()[0].to_string(),
+            "tod at row 1, column 3, reading down"
+        );
+        assert_eq!(search.missing(), vec!["dog"]);
+    }
+
+    #[test]
+    fn test_answer_key_shows_placed_letters() {
+        let grid = Grid::parse("cat/oxo/wed").unwrap();
+        let placement = grid.find("cow").unwrap();
+        assert_eq!(
+            grid.answer_key(&[placement]),
+            "    C . .\n    O . .\n    W . .\n"
+        );
+    }
+
+    #[test]
+    fn test_missing_theme_is_an_error() {
+        let mut solution = WordSearch::new("cat/oxo").unwrap();
+        assert_eq!(
+            solution
+                .load_theme(Some("no/such/theme.txt".to_string()))
+                .err(),
+            Some(Error::WordListRead(
+                "no/such/theme.txt".to_string(),
+                "No such file or directory (os error 2)".to_string()
+            ))
+        );
+    }
+}
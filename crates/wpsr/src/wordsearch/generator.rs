@@ -0,0 +1,359 @@
+use std::{fmt::Display, str::FromStr};
+
+use colorful::Colorful;
+use rand::{
+    SeedableRng,
+    seq::{IndexedRandom, SliceRandom},
+};
+use rand_chacha::ChaCha20Rng;
+
+use super::{Direction, Grid, Placement, read_theme};
+use crate::{Error, WordList};
+
+const DEFAULT_FILL_LETTERS: &str = "abcdefghijklmnopqrstuvwxyz";
+const MINIMUM_WORD_LENGTH: usize = 3;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum Directions {
+    #[default]
+    All,
+    Forward,
+}
+
+impl FromStr for Directions {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "all" => Ok(Self::All),
+            "forward" => Ok(Self::Forward),
+            _ => Err(format!("Invalid directions: {s}")),
+        }
+    }
+}
+
+impl Display for Directions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::All => write!(f, "All"),
+            Self::Forward => write!(f, "Forward"),
+        }
+    }
+}
+
+impl Directions {
+    fn allowed(&self) -> Vec<Direction> {
+        match self {
+            Directions::All => Direction::ALL.to_vec(),
+            Directions::Forward => vec![
+                Direction::Right,
+                Direction::DownRight,
+                Direction::Down,
+                Direction::UpRight,
+            ],
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct PuzzleGenerator<'a> {
+    rows: usize,
+    columns: usize,
+    words: Option<&'a WordList>,
+    targets: Vec<String>,
+    count: usize,
+    directions: Directions,
+    fill: Vec<char>,
+    seed: Option<u64>,
+    grid: Grid,
+    placements: Vec<Placement>,
+    skipped: Vec<String>,
+}
+
+impl<'a> PuzzleGenerator<'a> {
+    pub fn new(rows: usize, columns: usize) -> Result<Self, Error> {
+        if rows == 0 || columns == 0 {
+            return Err(Error::WordSearchGrid(format!("{rows}x{columns}")));
+        }
+
+        Ok(Self {
+            rows,
+            columns,
+            count: 10,
+            fill: DEFAULT_FILL_LETTERS.chars().collect(),
+            ..Default::default()
+        })
+    }
+
+    /// Word list to pick words from when no words to hide are given.
+    pub fn set_word_list(&mut self, words: &'a WordList) -> &mut Self {
+        self.words = Some(words);
+        self
+    }
+
+    /// Words to hide in the grid. When none are given the generator picks
+    /// words at random from the word list.
+    pub fn set_targets(&mut self, words: Vec<String>) -> &mut Self {
+        self.targets.extend(words.iter().map(|w| w.to_lowercase()));
+        self
+    }
+
+    pub fn load_theme(&mut self, theme: Option<String>) -> Result<&mut Self, Error> {
+        if let Some(path) = theme {
+            tracing::info!("Using theme: {}", path);
+            self.targets.extend(read_theme(&path)?);
+        }
+        Ok(self)
+    }
+
+    pub fn set_count(&mut self, value: usize) -> &mut Self {
+        self.count = value;
+        self
+    }
+
+    pub fn set_directions(&mut self, value: Directions) -> &mut Self {
+        self.directions = value;
+        self
+    }
+
+    /// Letters used to fill the cells not taken by words.
+    pub fn set_fill(&mut self, value: Option<String>) -> Result<&mut Self, Error> {
+        if let Some(letters) = value {
+            let letters = letters.to_ascii_lowercase();
+            if letters.is_empty() || !letters.chars().all(|c| c.is_ascii_lowercase()) {
+                return Err(Error::WordSearchFill(letters));
+            }
+            self.fill = letters.chars().collect();
+        }
+        Ok(self)
+    }
+
+    pub fn set_seed(&mut self, value: Option<u64>) -> &mut Self {
+        self.seed = value;
+        self
+    }
+
+    /// Place the words, longest first, at random positions where they fit
+    /// and fill the remaining cells with random letters.
+    #[tracing::instrument(skip(self))]
+    pub fn generate(&mut self) -> Result<&mut Self, Error> {
+        let mut rng = match self.seed {
+            Some(seed) => ChaCha20Rng::seed_from_u64(seed),
+            None => ChaCha20Rng::from_rng(&mut rand::rng()),
+        };
+
+        let mut words = if self.targets.is_empty() {
+            let longest = self.rows.max(self.columns);
+            let candidates = self
+                .words
+                .iter()
+                .flat_map(|words| words.iter())
+                .map(|w| w.to_lowercase())
+                .filter(|w| {
+                    (MINIMUM_WORD_LENGTH..=longest).contains(&w.len())
+                        && w.chars().all(|c| c.is_ascii_lowercase())
+                })
+                .collect::<Vec<String>>();
+            candidates
+                .sample(&mut rng, self.count)
+                .cloned()
+                .collect::<Vec<String>>()
+        } else {
+            self.targets.clone()
+        };
+        words.retain(|w| !w.is_empty() && w.chars().all(|c| c.is_ascii_lowercase()));
+        words.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        words.dedup();
+
+        let mut grid = Grid::new(self.rows, self.columns);
+        let mut placements = Vec::new();
+        let mut skipped = Vec::new();
+        let directions = self.directions.allowed();
+
+        for word in words {
+            let mut options = (0..self.rows * self.columns)
+                .flat_map(|cell| directions.iter().map(move |d| (cell, *d)))
+                .collect::<Vec<(usize, Direction)>>();
+            options.shuffle(&mut rng);
+
+            let letters = word.chars().collect::<Vec<char>>();
+            let spot = options.into_iter().find_map(|(cell, direction)| {
+                let (row, column) = (cell / self.columns, cell % self.columns);
+                let cells = grid.cells_for(letters.len(), row, column, direction)?;
+                let fits = cells
+                    .iter()
+                    .zip(letters.iter())
+                    .all(|(c, l)| grid.get(*c).is_none_or(|g| g == *l));
+                let covered = cells.iter().all(|c| grid.get(*c).is_some());
+                (fits && !covered).then_some((row, column, direction, cells))
+            });
+
+            match spot {
+                Some((row, column, direction, cells)) => {
+                    for (cell, letter) in cells.iter().zip(letters.iter()) {
+                        grid.set(*cell, *letter);
+                    }
+                    placements.push(Placement {
+                        word,
+                        row,
+                        column,
+                        direction,
+                    });
+                }
+                None => {
+                    tracing::info!("No room for {word}");
+                    skipped.push(word);
+                }
+            }
+        }
+
+        if placements.is_empty() {
+            return Err(Error::NoWordFound);
+        }
+
+        for cell in 0..self.rows * self.columns {
+            if grid.get(cell).is_none() {
+                grid.set(cell, *self.fill.choose(&mut rng).unwrap());
+            }
+        }
+
+        placements.sort_by(|a, b| a.word.cmp(&b.word));
+        self.grid = grid;
+        self.placements = placements;
+        self.skipped = skipped;
+
+        Ok(self)
+    }
+
+    pub fn grid(&self) -> &Grid {
+        &self.grid
+    }
+
+    pub fn placements(&self) -> &[Placement] {
+        &self.placements
+    }
+
+    pub fn word_source_string(&self) -> String {
+        match self.words {
+            Some(words) if !words.source().is_empty() => format!("{}\n", words.source_string()),
+            _ => String::new(),
+        }
+    }
+
+    pub fn puzzle_title(&self) -> String {
+        let intro = "Word search ";
+        let size = format!("{}x{}", self.rows, self.columns);
+        let mut ul = String::new();
+        for _ in 0..(intro.len() + size.len()) {
+            ul.push('‾');
+        }
+
+        let summary = format!("{}{}", intro.yellow().bold(), size.blue().bold());
+        format!("{}\n{}", summary, ul.bold().yellow())
+    }
+
+    /// The puzzle as plain text, the grid followed by the words to find.
+    pub fn puzzle_string(&self) -> String {
+        let mut s = self.grid.grid_string(None);
+        s.push('\n');
+        for placement in self.placements.iter() {
+            s.push_str(&format!("    {}\n", placement.word));
+        }
+        s
+    }
+
+    pub fn answer_key_string(&self) -> String {
+        let mut s = String::new();
+
+        s.push_str("  ");
+        s.push_str(&"Answer key".underlined().yellow().to_string());
+        s.push_str("\n\n");
+        s.push_str(&self.grid.answer_key(&self.placements));
+        s.push('\n');
+        for placement in self.placements.iter() {
+            s.push_str(&format!("    {placement}\n"));
+        }
+        if !self.skipped.is_empty() {
+            s.push_str(&format!(
+                "\n  No room in the grid for: {}\n",
+                self.skipped.join(", ")
+            ));
+        }
+        s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(words: &[&str]) -> Vec<String> {
+        words.iter().map(|w| w.to_string()).collect()
+    }
+
+    #[test]
+    fn test_generated_words_can_be_found() {
+        let mut generator = PuzzleGenerator::new(6, 6).unwrap();
+        generator
+            .set_targets(words(&["puzzle", "grid", "word", "letter", "find"]))
+            .set_seed(Some(3))
+            .generate()
+            .unwrap();
+
+        assert_eq!(generator.placements().len(), 5);
+        for placement in generator.placements() {
+            let found = generator.grid().find(&placement.word);
+            assert!(found.is_some(), "{} not found", placement.word);
+        }
+        assert!(!generator.grid().rows_string().contains('.'));
+    }
+
+    #[test]
+    fn test_forward_directions_only() {
+        let mut generator = PuzzleGenerator::new(5, 5).unwrap();
+        generator
+            .set_targets(words(&["cat", "dog", "emu", "owl", "yak", "eel"]))
+            .set_directions(Directions::Forward)
+            .set_seed(Some(11))
+            .generate()
+            .unwrap();
+
+        let allowed = Directions::Forward.allowed();
+        assert!(
+            generator
+                .placements()
+                .iter()
+                .all(|p| allowed.contains(&p.direction))
+        );
+    }
+
+    #[test]
+    fn test_fill_letters() {
+        let mut generator = PuzzleGenerator::new(3, 3).unwrap();
+        assert_eq!(
+            generator.set_fill(Some("ab1".to_string())).err(),
+            Some(Error::WordSearchFill("ab1".to_string()))
+        );
+
+        generator
+            .set_fill(Some("z".to_string()))
+            .unwrap()
+            .set_targets(words(&["cat"]))
+            .set_seed(Some(5))
+            .generate()
+            .unwrap();
+        let letters = generator.grid().rows_string().replace('/', "");
+        assert_eq!(letters.chars().filter(|c| *c == 'z').count(), 6);
+    }
+
+    #[test]
+    fn test_words_too_long_are_skipped() {
+        let mut generator = PuzzleGenerator::new(3, 3).unwrap();
+        generator
+            .set_targets(words(&["cat", "elephant"]))
+            .set_seed(Some(1))
+            .generate()
+            .unwrap();
+        assert_eq!(generator.placements().len(), 1);
+        assert_eq!(generator.skipped, vec!["elephant"]);
+    }
+}